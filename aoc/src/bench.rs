@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use common::input::{day_path, Source};
+
+use crate::days;
+
+/// Medians closer than this to the baseline are treated as noise, however
+/// large the relative change, so microsecond stages don't flap.
+const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|stage| stage.to_string() == name)
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn of(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+fn bench_day(dir: &Path, day: u8, iterations: usize) -> Result<Vec<Measurement>, String> {
+    let solution = days::get(day).ok_or_else(|| format!("no solver for day {}", day))?;
+    let input = Source::File(day_path(dir, day))
+        .read()
+        .map_err(|e| e.to_string())?;
+
+    let mut samples: HashMap<Stage, Vec<Duration>> = HashMap::new();
+    for _ in 0..iterations {
+        let timings = solution
+            .time(&input)
+            .map_err(|e| format!("day{}: {}", day, e))?;
+        samples.entry(Stage::Parse).or_default().push(timings.parse);
+        samples.entry(Stage::Part1).or_default().push(timings.part1);
+        samples.entry(Stage::Part2).or_default().push(timings.part2);
+    }
+
+    Ok(Stage::ALL
+        .into_iter()
+        .map(|stage| Measurement {
+            day,
+            stage,
+            stats: Stats::of(samples.remove(&stage).unwrap_or_default()),
+        })
+        .collect())
+}
+
+pub fn bench(
+    dir: &Path,
+    days: impl Iterator<Item = u8>,
+    iterations: usize,
+) -> Result<Vec<Measurement>, String> {
+    let mut measurements = vec![];
+    for day in days {
+        measurements.extend(bench_day(dir, day, iterations)?);
+    }
+    Ok(measurements)
+}
+
+/// Saved measurements to compare later runs against, one
+/// `day<N> <stage> <min_ns> <median_ns> <max_ns>` line per stage.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline(HashMap<(u8, Stage), Stats>);
+
+impl Baseline {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let parse_error = || format!("couldn't parse baseline {:?}", line);
+                let fields = line.split_whitespace().collect::<Vec<_>>();
+                let [day, stage, min, median, max] = fields[..] else {
+                    return Err(parse_error());
+                };
+                let day = day
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse().ok())
+                    .ok_or_else(parse_error)?;
+                let stage = Stage::parse(stage).ok_or_else(parse_error)?;
+                let nanos = |field: &str| {
+                    field
+                        .parse()
+                        .map(Duration::from_nanos)
+                        .map_err(|_| parse_error())
+                };
+                let stats = Stats {
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    max: nanos(max)?,
+                };
+                Ok(((day, stage), stats))
+            })
+            .collect::<Result<_, String>>()
+            .map(Baseline)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let input =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&input).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn save(path: &Path, measurements: &[Measurement]) -> Result<(), String> {
+        let output = measurements
+            .iter()
+            .map(|Measurement { day, stage, stats }| {
+                format!(
+                    "day{} {} {} {} {}\n",
+                    day,
+                    stage,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos()
+                )
+            })
+            .collect::<String>();
+        std::fs::write(path, output).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<&Stats> {
+        self.0.get(&(day, stage))
+    }
+}
+
+/// How a measurement's median moved against the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub baseline: Duration,
+    /// Relative change of the median, in percent.
+    pub percent: f64,
+}
+
+impl Change {
+    pub fn between(baseline: &Stats, current: &Stats) -> Self {
+        let before = baseline.median.as_secs_f64();
+        let after = current.median.as_secs_f64();
+        let percent = if before > 0.0 {
+            (after - before) / before * 100.0
+        } else {
+            0.0
+        };
+
+        Change {
+            baseline: baseline.median,
+            percent,
+        }
+    }
+
+    pub fn is_regression(&self, current: &Stats, threshold: f64) -> bool {
+        self.percent > threshold && current.median.saturating_sub(self.baseline) > NOISE_FLOOR
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:+.1}% vs {:.2?}", self.percent, self.baseline)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn it_summarises_samples() {
+        let stats = Stats::of(millis(&[5, 1, 3, 9, 4]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(4));
+        assert_eq!(stats.max, Duration::from_millis(9));
+    }
+
+    #[test]
+    fn it_round_trips_baselines() {
+        let stats = Stats::of(millis(&[1, 2, 3]));
+        let measurements = [Measurement {
+            day: 15,
+            stage: Stage::Part1,
+            stats,
+        }];
+
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+        Baseline::save(&path, &measurements).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(baseline.get(15, Stage::Part1), Some(&stats));
+        assert_eq!(baseline.get(15, Stage::Part2), None);
+    }
+
+    #[test]
+    fn it_rejects_bad_baselines() {
+        assert!(Baseline::parse("day15 part1 1 2").is_err());
+        assert!(Baseline::parse("day15 part3 1 2 3").is_err());
+        assert!(Baseline::parse("15 part1 1 2 3").is_err());
+    }
+
+    #[test]
+    fn it_flags_regressions() {
+        let baseline = Stats::of(millis(&[10]));
+        let slower = Stats::of(millis(&[12]));
+        let change = Change::between(&baseline, &slower);
+        assert_eq!(change.percent.round(), 20.0);
+        assert!(change.is_regression(&slower, 10.0));
+        assert!(!change.is_regression(&slower, 25.0));
+
+        let tiny = Stats::of(vec![Duration::from_micros(1)]);
+        let tiny_slower = Stats::of(vec![Duration::from_micros(3)]);
+        let change = Change::between(&tiny, &tiny_slower);
+        assert!(!change.is_regression(&tiny_slower, 10.0));
+    }
+}
@@ -0,0 +1,118 @@
+use std::time::{Duration, Instant};
+
+use common::{Answer, Error, Solution};
+
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=15;
+
+/// How long each stage of a single solve took.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// A day's solution with its types erased, so days can be picked at runtime.
+#[derive(Clone, Copy)]
+pub struct Day {
+    solve: fn(&str, u8) -> Result<Answer, Error>,
+    time: fn(&str) -> Result<Timings, Error>,
+}
+
+fn solve<S: Solution>(input: &str, part: u8) -> Result<Answer, Error> {
+    let solution = S::parse(input)?;
+    match part {
+        1 => solution.part1(),
+        _ => solution.part2(),
+    }
+}
+
+fn time<S: Solution>(input: &str) -> Result<Timings, Error> {
+    let start = Instant::now();
+    let solution = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    solution.part1()?;
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    solution.part2()?;
+    let part2 = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+impl Day {
+    fn of<S: Solution>() -> Self {
+        Day {
+            solve: solve::<S>,
+            time: time::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str, part: u8) -> Result<Answer, Error> {
+        (self.solve)(input, part)
+    }
+
+    pub fn time(&self, input: &str) -> Result<Timings, Error> {
+        (self.time)(input)
+    }
+}
+
+pub fn get(day: u8) -> Option<Day> {
+    Some(match day {
+        1 => Day::of::<day1::Day1>(),
+        2 => Day::of::<day2::Day2>(),
+        3 => Day::of::<day3::Day3>(),
+        4 => Day::of::<day4::Day4>(),
+        5 => Day::of::<day5::Day5>(),
+        6 => Day::of::<day6::Day6>(),
+        7 => Day::of::<day7::Day7>(),
+        8 => Day::of::<day8::Day8>(),
+        9 => Day::of::<day9::Day9>(),
+        10 => Day::of::<day10::Day10>(),
+        11 => Day::of::<day11::Day11>(),
+        12 => Day::of::<day12::Day12>(),
+        13 => Day::of::<day13::Day13>(),
+        14 => Day::of::<day14::Day14>(),
+        15 => Day::of::<day15::Day15>(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_dispatches_to_days() {
+        let day1 = get(1).unwrap();
+        assert_eq!(day1.solve("1122", 1), Ok(Answer::from(3u64)));
+        let day13 = get(13).unwrap();
+        assert_eq!(day13.solve("0: 3\n1: 2\n4: 4\n6: 4", 2), Ok(Answer::from(10usize)));
+        assert!(get(16).is_none());
+    }
+
+    #[test]
+    fn it_has_every_day() {
+        assert!(DAYS.clone().all(|day| get(day).is_some()));
+    }
+
+    #[test]
+    fn it_reports_parse_errors() {
+        let day1 = get(1).unwrap();
+        assert!(matches!(day1.solve("12a", 1), Err(Error::ParseError(_))));
+        assert!(matches!(day1.time("12a"), Err(Error::ParseError(_))));
+    }
+
+    #[test]
+    fn it_times_every_stage() {
+        let day13 = get(13).unwrap();
+        assert!(day13.time("0: 3\n1: 2\n4: 4\n6: 4").is_ok());
+    }
+}
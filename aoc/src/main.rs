@@ -0,0 +1,404 @@
+mod bench;
+mod days;
+mod output;
+mod verify;
+
+use common::input::{input_dir, InputArgs};
+use output::{BenchRecord, Format, RunRecord, VerifyRecord};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+const USAGE: &str = "usage: aoc [--format <text|json>] <command>
+    aoc run <day> [--part <1|2>] [--input <path|->] [--text <input>] [--input-dir <dir>]
+    aoc verify [<day>...] [--input-dir <dir>] [--record]
+    aoc bench [<day>...] [--iterations <n>] [--input-dir <dir>] [--save-baseline <path>]
+              [--baseline <path>] [--threshold <percent>]";
+
+#[derive(Debug)]
+enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        input: InputArgs,
+    },
+    Verify {
+        days: Vec<u8>,
+        dir: Option<PathBuf>,
+        record: bool,
+    },
+    Bench {
+        days: Vec<u8>,
+        dir: Option<PathBuf>,
+        iterations: usize,
+        save: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        threshold: f64,
+    },
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    let day = day.parse().map_err(|_| format!("day must be a number, got {:?}", day))?;
+    if days::DAYS.contains(&day) {
+        Ok(day)
+    } else {
+        Err(format!("no solver for day {}", day))
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = parse_day(&args.next().ok_or("no day")?)?;
+    let mut part = None;
+    let mut input = InputArgs::default();
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--part" | "-p" => {
+                let value = args.next().ok_or("--part needs a value")?;
+                part = Some(match value.as_str() {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => return Err(format!("unknown part {:?}", value)),
+                });
+            }
+            _ => {
+                if !input.parse_flag(&flag, &mut args)? {
+                    return Err(format!("unknown flag {:?}", flag));
+                }
+            }
+        }
+    }
+
+    Ok(Command::Run { day, part, input })
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut dir = None;
+    let mut record = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input-dir" => dir = Some(args.next().ok_or("--input-dir needs a value")?.into()),
+            "--record" => record = true,
+            _ if arg.starts_with('-') => return Err(format!("unknown flag {:?}", arg)),
+            _ => days.push(parse_day(&arg)?),
+        }
+    }
+
+    Ok(Command::Verify { days, dir, record })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut dir = None;
+    let mut iterations = 10;
+    let mut save = None;
+    let mut baseline = None;
+    let mut threshold = 10.0;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--input-dir" => dir = Some(value()?.into()),
+            "--iterations" | "-n" => {
+                iterations = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("--iterations must be a positive number")?
+            }
+            "--save-baseline" => save = Some(value()?.into()),
+            "--baseline" => baseline = Some(value()?.into()),
+            "--threshold" => {
+                threshold = value()?
+                    .parse()
+                    .map_err(|_| "--threshold must be a percentage")?
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown flag {:?}", arg)),
+            _ => days.push(parse_day(&arg)?),
+        }
+    }
+
+    Ok(Command::Bench {
+        days,
+        dir,
+        iterations,
+        save,
+        baseline,
+        threshold,
+    })
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(Command, Format), String> {
+    let mut format = Format::Text;
+    let mut rest = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = Format::parse(&args.next().ok_or("--format needs a value")?)?,
+            "--json" => format = Format::Json,
+            _ => rest.push(arg),
+        }
+    }
+
+    let mut args = rest.into_iter();
+    let command = match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("verify") => parse_verify(args),
+        Some("bench") => parse_bench(args),
+        Some(command) => Err(format!("unknown command {:?}", command)),
+        None => Err("no command".into()),
+    }?;
+
+    Ok((command, format))
+}
+
+fn run(day: u8, part: Option<u8>, input: InputArgs, format: Format) -> Result<(), String> {
+    let solution = days::get(day).ok_or_else(|| format!("no solver for day {}", day))?;
+    let input = input.source(day).read().map_err(|e| e.to_string())?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut records = vec![];
+    for part in parts {
+        let start = Instant::now();
+        let result = solution.solve(&input, part);
+        let record = RunRecord::new(day, part, result, start.elapsed());
+
+        if format == Format::Text {
+            match (&record.answer, &record.error) {
+                (Some(answer), _) => println!("day{} part{}: {}", day, part, answer),
+                (_, Some(e)) => return Err(e.clone()),
+                (None, None) => unreachable!("a part either answers or errors"),
+            }
+        }
+        records.push(record);
+    }
+
+    if format == Format::Json {
+        output::print_json(&records)?;
+    }
+
+    match records.iter().find_map(|record| record.error.as_ref()) {
+        Some(e) => Err(e.clone()),
+        None => Ok(()),
+    }
+}
+
+fn verify(days: Vec<u8>, dir: Option<PathBuf>, record: bool, format: Format) -> Result<(), String> {
+    let dir = dir.unwrap_or_else(input_dir);
+    let reports = if days.is_empty() {
+        verify::verify(&dir, days::DAYS)
+    } else {
+        verify::verify(&dir, days.into_iter())
+    };
+
+    match format {
+        Format::Text => {
+            for report in &reports {
+                println!("day{} part{}: {}", report.day, report.part, report.status);
+            }
+        }
+        Format::Json => {
+            output::print_json(&reports.iter().map(VerifyRecord::from).collect::<Vec<_>>())?
+        }
+    }
+
+    if record {
+        return verify::record(&dir, &reports);
+    }
+
+    let regressions = reports
+        .iter()
+        .filter(|report| report.status.is_regression())
+        .count();
+    if regressions > 0 {
+        Err(format!("{} of {} answers regressed", regressions, reports.len()))
+    } else {
+        Ok(())
+    }
+}
+
+fn bench(
+    days: Vec<u8>,
+    dir: Option<PathBuf>,
+    iterations: usize,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+    format: Format,
+) -> Result<(), String> {
+    let dir = dir.unwrap_or_else(input_dir);
+    let baseline = baseline
+        .map(|path| bench::Baseline::load(&path))
+        .transpose()?;
+    let measurements = if days.is_empty() {
+        bench::bench(&dir, days::DAYS, iterations)?
+    } else {
+        bench::bench(&dir, days.into_iter(), iterations)?
+    };
+
+    let mut regressions = 0;
+    let mut records = vec![];
+    for measurement in &measurements {
+        let stats = measurement.stats;
+        let change = baseline
+            .as_ref()
+            .and_then(|baseline| baseline.get(measurement.day, measurement.stage))
+            .map(|previous| bench::Change::between(previous, &stats));
+        let regression = change.is_some_and(|change| change.is_regression(&stats, threshold));
+        if regression {
+            regressions += 1;
+        }
+
+        match format {
+            Format::Text => {
+                let mut line = format!(
+                    "day{:<2} {}  min {:>10}  median {:>10}  max {:>10}",
+                    measurement.day,
+                    measurement.stage,
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.max),
+                );
+                if let Some(change) = change {
+                    line += &format!("  {}", change);
+                }
+                if regression {
+                    line += "  REGRESSION";
+                }
+                println!("{}", line);
+            }
+            Format::Json => records.push(BenchRecord::new(measurement, change, regression)),
+        }
+    }
+
+    if format == Format::Json {
+        output::print_json(&records)?;
+    }
+
+    if let Some(path) = save {
+        bench::Baseline::save(&path, &measurements)?;
+    }
+
+    if regressions > 0 {
+        Err(format!(
+            "{} of {} stages regressed by more than {}%",
+            regressions,
+            measurements.len(),
+            threshold
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    let (command, format) = match parse_args(std::env::args().skip(1)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run { day, part, input } => run(day, part, input, format),
+        Command::Verify { days, dir, record } => verify(days, dir, record, format),
+        Command::Bench {
+            days,
+            dir,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => bench(days, dir, iterations, save, baseline, threshold, format),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::input::Source;
+
+    fn args(line: &str) -> Result<Command, String> {
+        parse_args(line.split(' ').map(String::from)).map(|(command, _)| command)
+    }
+
+    #[test]
+    fn it_parses_args() {
+        let Command::Run { day, part, input } = args("run 7 --part 2 --input day7/input.txt").unwrap()
+        else {
+            panic!("expected a run command");
+        };
+        assert_eq!(day, 7);
+        assert_eq!(part, Some(2));
+        assert_eq!(input.source(7), Source::File("day7/input.txt".into()));
+    }
+
+    #[test]
+    fn it_reads_the_input_dir_by_default() {
+        let Command::Run { input, .. } = args("run 3 --input-dir puzzles").unwrap() else {
+            panic!("expected a run command");
+        };
+        assert_eq!(input.source(3), Source::File("puzzles/day3.txt".into()));
+    }
+
+    #[test]
+    fn it_rejects_unknown_parts() {
+        assert!(args("run 7 --part 3").is_err());
+        assert!(args("run 16").is_err());
+    }
+
+    #[test]
+    fn it_parses_verify_args() {
+        let Command::Verify { days, dir, record } = args("verify 7 14 --record").unwrap() else {
+            panic!("expected a verify command");
+        };
+        assert_eq!(days, vec![7, 14]);
+        assert_eq!(dir, None);
+        assert!(record);
+
+        assert!(args("verify --part 1").is_err());
+    }
+
+    #[test]
+    fn it_parses_the_output_format() {
+        let format = |line: &str| parse_args(line.split(' ').map(String::from)).map(|(_, f)| f);
+        assert_eq!(format("run 7"), Ok(Format::Text));
+        assert_eq!(format("--format json run 7"), Ok(Format::Json));
+        assert_eq!(format("verify --json"), Ok(Format::Json));
+        assert!(format("run 7 --format yaml").is_err());
+    }
+
+    #[test]
+    fn it_parses_bench_args() {
+        let Command::Bench {
+            days,
+            iterations,
+            save,
+            threshold,
+            ..
+        } = args("bench 15 --iterations 5 --save-baseline base.txt --threshold 2.5").unwrap()
+        else {
+            panic!("expected a bench command");
+        };
+        assert_eq!(days, vec![15]);
+        assert_eq!(iterations, 5);
+        assert_eq!(save, Some(PathBuf::from("base.txt")));
+        assert_eq!(threshold, 2.5);
+
+        assert!(args("bench --iterations 0").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use common::{Answer, Error};
+use serde::Serialize;
+
+use crate::bench::{Change, Measurement};
+use crate::verify::{Report, Status};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {:?}", name)),
+        }
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// One part solved by `aoc run`.
+#[derive(Debug, Serialize)]
+pub struct RunRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub duration_ns: u64,
+    pub error: Option<String>,
+}
+
+impl RunRecord {
+    pub fn new(day: u8, part: u8, result: Result<Answer, Error>, duration: Duration) -> Self {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        RunRecord {
+            day,
+            part,
+            answer,
+            duration_ns: nanos(duration),
+            error,
+        }
+    }
+}
+
+/// One part checked by `aoc verify`.
+#[derive(Debug, Serialize)]
+pub struct VerifyRecord<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub status: &'static str,
+    pub answer: Option<&'a str>,
+    pub expected: Option<&'a str>,
+    pub duration_ns: u64,
+    pub error: Option<&'a str>,
+}
+
+impl<'a> From<&'a Report> for VerifyRecord<'a> {
+    fn from(report: &'a Report) -> Self {
+        let (status, answer, expected, error) = match &report.status {
+            Status::Pass(answer) => ("pass", Some(answer), Some(answer), None),
+            Status::Mismatch { expected, actual } => {
+                ("mismatch", Some(actual), Some(expected), None)
+            }
+            Status::Fail(e) => ("fail", None, None, Some(e)),
+            Status::Missing(answer) => ("missing", Some(answer), None, None),
+        };
+        VerifyRecord {
+            day: report.day,
+            part: report.part,
+            status,
+            answer: answer.map(String::as_str),
+            expected: expected.map(String::as_str),
+            duration_ns: nanos(report.duration),
+            error: error.map(String::as_str),
+        }
+    }
+}
+
+/// One stage timed by `aoc bench`.
+#[derive(Debug, Serialize)]
+pub struct BenchRecord {
+    pub day: u8,
+    pub stage: String,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    pub baseline_median_ns: Option<u64>,
+    pub change_percent: Option<f64>,
+    pub regression: bool,
+}
+
+impl BenchRecord {
+    pub fn new(measurement: &Measurement, change: Option<Change>, regression: bool) -> Self {
+        let stats = measurement.stats;
+        BenchRecord {
+            day: measurement.day,
+            stage: measurement.stage.to_string(),
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            max_ns: nanos(stats.max),
+            baseline_median_ns: change.map(|change| nanos(change.baseline)),
+            change_percent: change.map(|change| change.percent),
+            regression,
+        }
+    }
+}
+
+pub fn print_json<T: Serialize>(records: &[T]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(records).map_err(|e| e.to_string())?;
+    println!("{}", json);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_serializes_run_records() {
+        let record = RunRecord::new(7, 1, Ok("cqmvs".into()), Duration::from_micros(3));
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":7,"part":1,"answer":"cqmvs","duration_ns":3000,"error":null}"#
+        );
+
+        let record = RunRecord::new(
+            1,
+            2,
+            Err(Error::ParseError("'a' isn't a digit".into())),
+            Duration::ZERO,
+        );
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":1,"part":2,"answer":null,"duration_ns":0,"error":"parse error: 'a' isn't a digit"}"#
+        );
+    }
+
+    #[test]
+    fn it_serializes_verify_records() {
+        let report = Report {
+            day: 7,
+            part: 2,
+            status: Status::Mismatch {
+                expected: "2310".into(),
+                actual: "2311".into(),
+            },
+            duration: Duration::from_nanos(5),
+        };
+        assert_eq!(
+            serde_json::to_string(&VerifyRecord::from(&report)).unwrap(),
+            r#"{"day":7,"part":2,"status":"mismatch","answer":"2311","expected":"2310","duration_ns":5,"error":null}"#
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use common::input::{day_path, Source};
+
+use crate::days;
+
+/// Known-good answers for a day, stored next to its input as
+/// `day<N>.answers` with one `part<N>: <answer>` line per part.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn path(dir: &Path, day: u8) -> PathBuf {
+        dir.join(format!("day{}.answers", day))
+    }
+
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut answers = Answers::default();
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let (part, answer) = line
+                .split_once(':')
+                .ok_or_else(|| format!("couldn't parse answer {:?}", line))?;
+            let answer = Some(answer.trim().to_string());
+            match part.trim() {
+                "part1" => answers.part1 = answer,
+                "part2" => answers.part2 = answer,
+                _ => return Err(format!("unknown part {:?}", part)),
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn load(dir: &Path, day: u8) -> Result<Self, String> {
+        let path = Self::path(dir, day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => Self::parse(&input).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            _ => self.part2 = Some(answer),
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in [(1, &self.part1), (2, &self.part2)] {
+            if let Some(answer) = answer {
+                writeln!(f, "part{}: {}", part, answer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass(String),
+    Mismatch { expected: String, actual: String },
+    /// The solver errored or the input couldn't be read.
+    Fail(String),
+    /// Nothing is recorded for this part yet.
+    Missing(String),
+}
+
+impl Status {
+    pub fn check(expected: Option<&str>, actual: Result<String, String>) -> Self {
+        match (expected, actual) {
+            (_, Err(e)) => Status::Fail(e),
+            (None, Ok(actual)) => Status::Missing(actual),
+            (Some(expected), Ok(actual)) if expected == actual => Status::Pass(actual),
+            (Some(expected), Ok(actual)) => Status::Mismatch {
+                expected: expected.to_string(),
+                actual,
+            },
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Status::Mismatch { .. } | Status::Fail(_))
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass(answer) => write!(f, "pass ({})", answer),
+            Status::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH expected {}, got {}", expected, actual)
+            }
+            Status::Fail(e) => write!(f, "FAIL {}", e),
+            Status::Missing(answer) => write!(f, "missing ({})", answer),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub duration: Duration,
+}
+
+fn verify_day(dir: &Path, day: u8) -> Vec<Report> {
+    let solution = days::get(day).expect("every day has a solution");
+    let input = Source::File(day_path(dir, day)).read();
+    let answers = Answers::load(dir, day);
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let status = match (&input, &answers) {
+                (Err(e), _) => Status::Fail(e.to_string()),
+                (_, Err(e)) => Status::Fail(e.clone()),
+                (Ok(input), Ok(answers)) => Status::check(
+                    answers.get(part),
+                    solution
+                        .solve(input, part)
+                        .map(|answer| answer.to_string())
+                        .map_err(|e| e.to_string()),
+                ),
+            };
+            Report {
+                day,
+                part,
+                status,
+                duration: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+pub fn verify(dir: &Path, days: impl Iterator<Item = u8>) -> Vec<Report> {
+    days.flat_map(|day| verify_day(dir, day)).collect()
+}
+
+/// Writes the answers of every part that solved into its day's answers file,
+/// keeping whatever was recorded for parts that failed.
+pub fn record(dir: &Path, reports: &[Report]) -> Result<(), String> {
+    let mut days = reports.iter().map(|report| report.day).collect::<Vec<_>>();
+    days.dedup();
+
+    for day in days {
+        let mut answers = Answers::load(dir, day)?;
+        for report in reports.iter().filter(|report| report.day == day) {
+            match &report.status {
+                Status::Pass(actual)
+                | Status::Missing(actual)
+                | Status::Mismatch { actual, .. } => answers.set(report.part, actual.clone()),
+                Status::Fail(_) => {}
+            }
+        }
+
+        let path = Answers::path(dir, day);
+        std::fs::write(&path, answers.to_string())
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_answers() {
+        let answers = Answers::parse("part1: cqmvs\npart2: 2310\n").unwrap();
+        assert_eq!(answers.get(1), Some("cqmvs"));
+        assert_eq!(answers.get(2), Some("2310"));
+        assert_eq!(answers.to_string(), "part1: cqmvs\npart2: 2310\n");
+
+        let answers = Answers::parse("part2: 2310").unwrap();
+        assert_eq!(answers.get(1), None);
+
+        assert!(Answers::parse("part3: 1").is_err());
+        assert!(Answers::parse("2310").is_err());
+    }
+
+    #[test]
+    fn it_checks_answers() {
+        assert_eq!(Status::check(Some("3"), Ok("3".into())), Status::Pass("3".into()));
+        assert_eq!(
+            Status::check(Some("3"), Ok("4".into())),
+            Status::Mismatch {
+                expected: "3".into(),
+                actual: "4".into()
+            }
+        );
+        assert_eq!(Status::check(None, Ok("4".into())), Status::Missing("4".into()));
+        assert!(Status::check(Some("3"), Err("parse error".into())).is_regression());
+        assert!(!Status::check(None, Ok("4".into())).is_regression());
+    }
+}
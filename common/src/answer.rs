@@ -0,0 +1,70 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Serializes as a bare JSON number or string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(num) => write!(f, "{}", num),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! from_number {
+    ($($num:ty),*) => {
+        $(
+            impl From<$num> for Answer {
+                fn from(num: $num) -> Self {
+                    Answer::Number(i64::try_from(num).expect("answer doesn't fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_displays_answers() {
+        assert_eq!(Answer::from(1158u64).to_string(), "1158");
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from("cqmvs").to_string(), "cqmvs");
+    }
+
+    #[test]
+    fn it_compares_answers() {
+        assert_eq!(Answer::from(5usize), Answer::from(5u32));
+        assert_ne!(Answer::from(5usize), Answer::from("5"));
+    }
+
+    #[test]
+    fn it_serializes_answers_bare() {
+        assert_eq!(serde_json::to_string(&Answer::from(1158u64)).unwrap(), "1158");
+        assert_eq!(serde_json::to_string(&Answer::from("cqmvs")).unwrap(), "\"cqmvs\"");
+    }
+}
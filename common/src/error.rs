@@ -0,0 +1,23 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input couldn't be read.
+    InputError(String),
+    /// The input couldn't be turned into the day's puzzle.
+    ParseError(String),
+    /// The input parsed but has no answer for the requested part.
+    NoSolution(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InputError(reason) => write!(f, "input error: {}", reason),
+            Error::ParseError(reason) => write!(f, "parse error: {}", reason),
+            Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,158 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+
+/// Where inputs live when no source is given: `$AOC_INPUT_DIR`, or `inputs`
+/// relative to the working directory. Each day reads `day<N>.txt` from it.
+pub const INPUT_DIR: &str = "inputs";
+
+pub const USAGE: &str = "[--input <path|->] [--text <input>] [--input-dir <dir>]";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    /// Reads the whole input, dropping trailing whitespace so every day sees
+    /// the same text whether it came from a file, a pipe or the command line.
+    pub fn read(&self) -> Result<String, Error> {
+        let input = match self {
+            Source::File(path) => std::fs::read_to_string(path)
+                .map_err(|e| Error::InputError(format!("{}: {}", path.display(), e)))?,
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::InputError(format!("stdin: {}", e)))?;
+                input
+            }
+            Source::Text(text) => text.clone(),
+        };
+
+        Ok(input.trim_end().to_string())
+    }
+}
+
+pub fn input_dir() -> PathBuf {
+    std::env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(INPUT_DIR))
+}
+
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{}.txt", day))
+}
+
+/// The input flags shared by the runner and every day's binary.
+#[derive(Debug, Default)]
+pub struct InputArgs {
+    pub source: Option<Source>,
+    pub dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    /// Takes `flag` and its value from `args` if it's an input flag, returning
+    /// whether it was consumed.
+    pub fn parse_flag(
+        &mut self,
+        flag: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", flag));
+
+        match flag {
+            "--input" | "-i" => {
+                let path = value()?;
+                self.source = Some(if path == "-" {
+                    Source::Stdin
+                } else {
+                    Source::File(path.into())
+                });
+            }
+            "--text" | "-t" => self.source = Some(Source::Text(value()?)),
+            "--input-dir" => self.dir = Some(value()?.into()),
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    pub fn source(&self, day: u8) -> Source {
+        match &self.source {
+            Some(source) => source.clone(),
+            None => {
+                let dir = self.dir.clone().unwrap_or_else(input_dir);
+                Source::File(day_path(&dir, day))
+            }
+        }
+    }
+}
+
+/// Reads a day's input as chosen by the process arguments.
+pub fn load(day: u8) -> Result<String, Error> {
+    let mut input_args = InputArgs::default();
+    let mut args = std::env::args().skip(1);
+
+    while let Some(flag) = args.next() {
+        if !input_args
+            .parse_flag(&flag, &mut args)
+            .map_err(Error::InputError)?
+        {
+            return Err(Error::InputError(format!(
+                "unknown flag {:?}\nusage: day{} {}",
+                flag, day, USAGE
+            )));
+        }
+    }
+
+    input_args.source(day).read()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(line: &str) -> Result<InputArgs, String> {
+        let mut input_args = InputArgs::default();
+        let mut args = line.split(' ').map(String::from);
+        while let Some(flag) = args.next() {
+            if !input_args.parse_flag(&flag, &mut args)? {
+                return Err(flag);
+            }
+        }
+        Ok(input_args)
+    }
+
+    #[test]
+    fn it_defaults_to_the_input_dir() {
+        let input_args = parse("--input-dir puzzles").unwrap();
+        assert_eq!(
+            input_args.source(7),
+            Source::File(PathBuf::from("puzzles/day7.txt"))
+        );
+    }
+
+    #[test]
+    fn it_parses_sources() {
+        assert_eq!(
+            parse("--input day7.txt").unwrap().source(7),
+            Source::File("day7.txt".into())
+        );
+        assert_eq!(parse("--input -").unwrap().source(7), Source::Stdin);
+        assert_eq!(
+            parse("--text 265149").unwrap().source(3),
+            Source::Text("265149".into())
+        );
+        assert!(parse("--text").is_err());
+        assert!(parse("--part 1").is_err());
+    }
+
+    #[test]
+    fn it_trims_trailing_whitespace() {
+        assert_eq!(Source::Text("1122\n".into()).read().unwrap(), "1122");
+    }
+}
@@ -0,0 +1,16 @@
+mod answer;
+mod error;
+pub mod input;
+
+pub use answer::Answer;
+pub use error::Error;
+
+/// A day's puzzle: the input is parsed once and both parts are answered from
+/// the parsed form.
+pub trait Solution: Sized {
+    fn parse(input: &str) -> Result<Self, Error>;
+
+    fn part1(&self) -> Result<Answer, Error>;
+
+    fn part2(&self) -> Result<Answer, Error>;
+}
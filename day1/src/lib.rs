@@ -0,0 +1,86 @@
+use common::{Answer, Error, Solution};
+
+pub struct Day1(String);
+
+impl Solution for Day1 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let input = input.trim();
+        match input.chars().find(|c| !c.is_ascii_digit()) {
+            Some(c) => Err(Error::ParseError(format!("{:?} isn't a digit", c))),
+            None => Ok(Self(input.to_string())),
+        }
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(solver::solve1(&self.0).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(solver::solve2(&self.0).into())
+    }
+}
+
+pub mod solver {
+    pub fn solve1(input: &str) -> u64 {
+        let count = input.chars().count();
+        let mut iter = input.chars().cycle().peekable();
+
+        let mut result = 0;
+
+        for _ in 0..count {
+            if let Some(current) = iter.next() {
+                if let Some(next) = iter.peek() {
+                    if &current == next {
+                        result += current.to_digit(10).expect("unknown character") as u64;
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn solve2(input: &str) -> u64 {
+        let count = input.chars().count();
+        let mut iter = input.chars().cycle();
+        let mut iter_half = input.chars().cycle();
+        for _ in 0..count / 2 {
+            iter_half.next();
+        }
+
+        let mut result = 0;
+
+        for _ in 0..count {
+            if let Some(current) = iter.next() {
+                if let Some(next) = iter_half.next() {
+                    if current == next {
+                        result += current.to_digit(10).expect("unknown character") as u64;
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_one() {
+        let input = "1122";
+        assert_eq!(solver::solve1(input), 3);
+        assert_eq!(solver::solve1("1111"), 4);
+        assert_eq!(solver::solve1("1234"), 0);
+        assert_eq!(solver::solve1("91212129"), 9);
+    }
+
+    #[test]
+    fn test_two() {
+        assert_eq!(solver::solve2("1212"), 6);
+        assert_eq!(solver::solve2("1221"), 0);
+        assert_eq!(solver::solve2("123425"), 4);
+    }
+}
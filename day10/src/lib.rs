@@ -0,0 +1,154 @@
+use common::{Answer, Solution};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A comma separated length that can't be used, with its index in the
+    /// list and why.
+    InvalidLength {
+        token: String,
+        index: usize,
+        reason: String,
+    },
+    InvalidListSize(i32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength {
+                token,
+                index,
+                reason,
+            } => write!(
+                f,
+                "invalid length {:?} at index {}: {}",
+                token, index, reason
+            ),
+            Error::InvalidListSize(size) => {
+                write!(f, "list size {} isn't between 2 and 256", size)
+            }
+        }
+    }
+}
+
+impl From<Error> for common::Error {
+    fn from(error: Error) -> Self {
+        common::Error::ParseError(error.to_string())
+    }
+}
+
+pub struct Day10(String);
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self, common::Error> {
+        Ok(Self(input.trim().to_string()))
+    }
+
+    fn part1(&self) -> Result<Answer, common::Error> {
+        Ok(part1(&self.0, 256)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, common::Error> {
+        Ok(part2(&self.0).into())
+    }
+}
+
+/// Part1 multiplies the first two marks, and marks are bytes.
+fn list_size(buffer_length: i32) -> Result<usize, Error> {
+    usize::try_from(buffer_length)
+        .ok()
+        .filter(|size| (2..=256).contains(size))
+        .ok_or(Error::InvalidListSize(buffer_length))
+}
+
+/// Lengths longer than the list are invalid, as the puzzle says, rather than
+/// reversing part of the circle twice.
+fn parse_lengths(input: &str, size: usize) -> Result<Vec<usize>, Error> {
+    input
+        .split(',')
+        .enumerate()
+        .map(|(index, token)| {
+            let invalid = |reason: String| Error::InvalidLength {
+                token: token.to_string(),
+                index,
+                reason,
+            };
+            let length = match token.trim() {
+                "" => return Err(invalid("missing length".into())),
+                trimmed => trimmed
+                    .parse::<usize>()
+                    .map_err(|_| invalid("not a non-negative whole number".into()))?,
+            };
+            if length > size {
+                return Err(invalid(format!(
+                    "length {} exceeds list size {}",
+                    length, size
+                )));
+            }
+            Ok(length)
+        })
+        .collect()
+}
+
+pub fn part1(input: &str, buffer_length: i32) -> Result<i32, Error> {
+    let size = list_size(buffer_length)?;
+    let lengths = parse_lengths(input, size)?;
+
+    let knot = knothash::Params::single_round(size).sparse(&lengths);
+    Ok(knot.list()[0] as i32 * knot.list()[1] as i32)
+}
+
+/// Every step part1 takes, to see where the circle goes wrong.
+pub fn trace_part1(input: &str, buffer_length: i32) -> Result<knothash::Trace, Error> {
+    let size = list_size(buffer_length)?;
+    Ok(knothash::Params::single_round(size).trace(&parse_lengths(input, size)?))
+}
+
+pub fn part2(input: &str) -> String {
+    knothash::to_hex(&knothash::hash(input.as_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1("3,4,1,5", 5).unwrap(), 12)
+    }
+
+    #[test]
+    fn it_rejects_bad_lengths() {
+        assert_eq!(
+            part1("14,300,2", 256).unwrap_err().to_string(),
+            "invalid length \"300\" at index 1: length 300 exceeds list size 256"
+        );
+        assert_eq!(
+            part1("3,4,-1", 5),
+            Err(Error::InvalidLength {
+                token: "-1".into(),
+                index: 2,
+                reason: "not a non-negative whole number".into(),
+            })
+        );
+        assert_eq!(
+            part1("3,,1", 5).unwrap_err().to_string(),
+            "invalid length \"\" at index 1: missing length"
+        );
+        assert_eq!(part1("3, 4, 1, 5", 5), Ok(12));
+        assert_eq!(part1("5", 5), Ok(12));
+        assert_eq!(part1("1", 300), Err(Error::InvalidListSize(300)));
+    }
+
+    #[test]
+    fn trace_part1_works() {
+        let trace = trace_part1("3,4,1,5", 5).unwrap();
+        assert_eq!(trace.steps.last().unwrap().list, vec![3, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d")
+    }
+}
@@ -1,104 +1,76 @@
-use core::fmt;
-
-#[derive(Debug)]
-enum Error {
-    ParseError,
-}
-
-fn part1(input: &str, buffer_length: i32) -> Result<i32, Error> {
-    let lengths = input
-        .split(',')
-        .map(|num| num.parse().map_err(|_| Error::ParseError))
-        .collect::<Result<Vec<i32>, Error>>()?;
-    let mut current_position = 0;
-    let mut buffer = (0..buffer_length).collect::<Vec<_>>();
-
-    for (skip_size, length) in lengths.into_iter().enumerate() {
-        let mut temp_buffer = buffer.iter().cycle();
-
-        // replace with advance_by
-        for _ in 0..current_position {
-            temp_buffer.next();
-        }
-
-        let buffer_copy = temp_buffer
-            .take(length as usize)
-            .cloned()
-            .collect::<Vec<i32>>();
-
-        for num in buffer_copy.iter().rev() {
-            buffer[current_position as usize % buffer_length as usize] = *num;
-            current_position += 1;
-        }
-        current_position += skip_size;
-    }
-
-    Ok(buffer[0] * buffer[1])
+use common::input::{InputArgs, USAGE};
+use common::Solution;
+use day10::Day10;
+
+/// How to print part 1's steps instead of the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trace {
+    Text,
+    Json,
 }
-fn part2(input: &str, buffer_length: i32) -> Result<String, Error> {
-    let lengths = input
-        .chars()
-        .map(|char| char as u8)
-        .chain([17, 31, 73, 47, 23u8].into_iter());
-    let mut current_position = 0;
-    let mut buffer = (0..buffer_length).collect::<Vec<_>>();
-    let mut skip_size = 0;
-
-    for _ in 0..64 {
-        for length in lengths.clone() {
-            let mut temp_buffer = buffer.iter().cycle();
 
-            // replace with advance_by
-            for _ in 0..current_position {
-                temp_buffer.next();
+/// The input flags, then the trace format if any and the list size to trace
+/// part 1 over.
+fn parse_args() -> Result<(InputArgs, Option<Trace>, i32), common::Error> {
+    let mut input_args = InputArgs::default();
+    let mut trace = None;
+    let mut size = 256;
+    let mut args = std::env::args().skip(1).peekable();
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--trace" => {
+                // the format is optional, so only take a word that names one
+                trace = Some(match args.peek().map(String::as_str) {
+                    Some("json") => Trace::Json,
+                    Some("text") => Trace::Text,
+                    _ => {
+                        trace = Some(Trace::Text);
+                        continue;
+                    }
+                });
+                args.next();
             }
-
-            let buffer_copy = temp_buffer
-                .take(length as usize)
-                .cloned()
-                .collect::<Vec<i32>>();
-
-            for num in buffer_copy.iter().rev() {
-                buffer[current_position as usize % buffer_length as usize] = *num;
-                current_position += 1;
+            "--size" => {
+                size = args
+                    .next()
+                    .and_then(|size| size.parse().ok())
+                    .ok_or_else(|| common::Error::InputError("--size needs a number".into()))?
+            }
+            _ => {
+                if !input_args
+                    .parse_flag(&flag, &mut args)
+                    .map_err(common::Error::InputError)?
+                {
+                    return Err(common::Error::InputError(format!(
+                        "unknown flag {:?}\nusage: day10 {} [--trace [text|json]] [--size <n>]",
+                        flag, USAGE
+                    )));
+                }
             }
-            current_position += skip_size;
-            skip_size += 1;
         }
     }
 
-    Ok(buffer
-        .chunks(16)
-        .map(|chunk| {
-            format!(
-                "{:0>2x}",
-                chunk.iter().cloned().reduce(|a, b| a ^ b).unwrap()
-            )
-        })
-        .collect::<String>())
+    Ok((input_args, trace, size))
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+fn main() -> Result<(), common::Error> {
+    let (input_args, trace, size) = parse_args()?;
+    let input = input_args.source(10).read()?;
 
-    #[test]
-    fn part1_works() {
-        assert_eq!(part1("3,4,1,5", 5).unwrap(), 12)
+    if let Some(format) = trace {
+        let trace = day10::trace_part1(input.trim(), size)?;
+        match format {
+            Trace::Text => print!("{}", trace.render()),
+            Trace::Json => println!("{}", trace.to_json()),
+        }
+        return Ok(());
     }
 
-    #[test]
-    fn part2_works() {
-        assert_eq!(
-            part2("1,2,3", 256).unwrap(),
-            "3efbe78a8d82f29979031a4aa0b16a9d"
-        )
-    }
-}
+    let day = Day10::parse(&input)?;
 
-fn main() {
-    let input = "14,58,0,116,179,16,1,104,2,254,167,86,255,55,122,244";
+    println!("part1: {}", day.part1()?);
+    println!("part2: {}", day.part2()?);
 
-    println!("part1: {:?}", part1(input, 256));
-    println!("part2: {:?}", part2(input, 256));
+    Ok(())
 }
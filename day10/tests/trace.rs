@@ -0,0 +1,48 @@
+use std::process::Command;
+
+fn day10(args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_day10"))
+        .args(args)
+        .output()
+        .expect("couldn't run day10");
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn it_traces_part1_as_text() {
+    let (ok, stdout, _) = day10(&["--text", "3,4,1,5", "--size", "5", "--trace"]);
+    assert!(ok);
+    assert_eq!(
+        stdout,
+        "round 1, length 3 at 0, skip 0:\n(2 1 0) [3] 4\n\n\
+         round 1, length 4 at 3, skip 1:\n4 3) 0 ([1] 2\n\n\
+         round 1, length 1 at 3, skip 2:\n4 [3] 0 (1) 2\n\n\
+         round 1, length 5 at 1, skip 3:\n3) (4 2 1 [0]\n"
+    );
+}
+
+#[test]
+fn it_traces_part1_as_json() {
+    let (ok, stdout, _) = day10(&["--trace", "json", "--size", "5", "--text", "3,4,1,5"]);
+    assert!(ok);
+    let trace: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(trace["list_size"], 5);
+    let steps = trace["steps"].as_array().unwrap();
+    assert_eq!(steps.len(), 4);
+    assert_eq!(steps[3]["list"], serde_json::json!([3, 4, 2, 1, 0]));
+}
+
+#[test]
+fn it_reports_bad_lengths_when_tracing() {
+    let (ok, _, stderr) = day10(&["--text", "3,9", "--size", "5", "--trace"]);
+    assert!(!ok);
+    assert!(
+        stderr.contains("length 9 exceeds list size 5"),
+        "{}",
+        stderr
+    );
+}
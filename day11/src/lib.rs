@@ -0,0 +1,101 @@
+use common::{Answer, Error, Solution};
+use grid::Point;
+
+pub enum Direction {
+    South,
+    SouthWest,
+    SouthEast,
+    North,
+    NorthWest,
+    NorthEast,
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        use Direction::*;
+        Ok(match input {
+            "s" => South,
+            "sw" => SouthWest,
+            "se" => SouthEast,
+            "n" => North,
+            "nw" => NorthWest,
+            "ne" => NorthEast,
+            _ => return Err(Error::ParseError(format!("unknown direction {:?}", input))),
+        })
+    }
+}
+
+/// Hexes sit on "doubled" coordinates: a step north or south moves two rows,
+/// a diagonal step moves one row and one column.
+impl Direction {
+    fn offset(&self) -> Point {
+        match self {
+            Direction::South => Point::new(0, 2),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::North => Point::new(0, -2),
+            Direction::NorthWest => Point::new(-1, -1),
+            Direction::NorthEast => Point::new(1, -1),
+        }
+    }
+
+    fn apply(&self, point: Point) -> Point {
+        point + self.offset()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Direction>, Error> {
+    input.trim().split(',').map(|d| d.try_into()).collect()
+}
+
+pub struct Day11(Vec<Direction>);
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self(parse_input(input)?))
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(part1(&self.0).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(part2(&self.0).into())
+    }
+}
+
+fn distance(point: Point) -> i32 {
+    point.x.abs() + (point.y.abs() - point.x.abs()).max(0) / 2
+}
+
+pub fn part1(directions: &[Direction]) -> i32 {
+    distance(directions.iter().fold(Point::ORIGIN, |memo, direction| direction.apply(memo)))
+}
+
+pub fn part2(directions: &[Direction]) -> i32 {
+    let (_, max) = directions.iter().fold((Point::ORIGIN, 0), |(point, max), direction| {
+        let point = direction.apply(point);
+        (point, max.max(distance(point)))
+    });
+    max
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn direction_apply_works() {
+        assert_eq!(Direction::NorthEast.apply(Point::ORIGIN), Point::new(1, -1));
+        assert_eq!(Direction::South.apply(Point::ORIGIN), Point::new(0, 2));
+    }
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(&parse_input("ne,ne,ne").unwrap()), 3);
+        assert_eq!(part1(&parse_input("ne,ne,sw,sw").unwrap()), 0);
+        assert_eq!(part1(&parse_input("ne,ne,s,s").unwrap()), 2);
+        assert_eq!(part1(&parse_input("se,sw,se,sw,sw").unwrap()), 3);
+    }
+}
@@ -0,0 +1,72 @@
+use common::{Answer, Error, Solution};
+
+type Pipes = graph::Graph<i32>;
+
+fn parse_input(input: &str) -> Result<Pipes, Error> {
+    let mut pipes = Pipes::new();
+    for line in input.lines() {
+        let (left, right) = line
+            .trim()
+            .split_once("<->")
+            .ok_or_else(|| Error::ParseError(format!("couldn't parse line {:?}", line)))?;
+        let left = left
+            .trim()
+            .parse::<i32>()
+            .map_err(|_| Error::ParseError(format!("couldn't parse left {}", left)))?;
+
+        pipes.add_node(left);
+        for num in right.split(", ") {
+            let right = num
+                .trim()
+                .parse::<i32>()
+                .map_err(|_| Error::ParseError(format!("couldn't parse right {:?}", num)))?;
+            pipes.add_edge(left, right);
+        }
+    }
+    Ok(pipes)
+}
+
+pub struct Day12(Pipes);
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self(parse_input(input)?))
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(part1(&self.0).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(part2(&self.0).into())
+    }
+}
+
+pub fn part1(pipes: &Pipes) -> usize {
+    pipes.bfs(&0).count()
+}
+
+pub fn part2(pipes: &Pipes) -> usize {
+    pipes.components().len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let pipes = parse_input(
+            "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5",
+        )
+        .unwrap();
+        assert_eq!(part1(&pipes), 6);
+        assert_eq!(part2(&pipes), 2);
+    }
+}
@@ -0,0 +1,83 @@
+use common::{Answer, Error, Solution};
+
+#[derive(Debug)]
+struct Layer {
+    depth: usize,
+    range: usize,
+    cost: usize,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Layer>, Error> {
+    input.lines().map(|line| {
+        let parse_error = || Error::ParseError(format!("couldn't parse layer {:?}", line));
+        let (depth, range) = line.split_once(": ").ok_or_else(parse_error)?;
+        let depth: usize = depth.parse().map_err(|_| parse_error())?;
+        let range: usize = range.parse().map_err(|_| parse_error())?;
+        if range < 2 {
+            return Err(parse_error());
+        }
+
+        Ok(Layer {
+            depth,
+            range,
+            cost: depth * range,
+        })
+    }).collect()
+}
+
+pub struct Day13(Vec<Layer>);
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self(parse_input(input)?))
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(part1(&self.0).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(part2(&self.0).into())
+    }
+}
+
+fn part1(layers: &[Layer]) -> usize {
+    layers.iter().map(|layer| {
+        if layer.depth % (layer.range + layer.range - 2) == 0 {
+            layer.cost
+        } else {
+            0
+        }
+    }).sum()
+}
+
+fn part2(layers: &[Layer]) -> usize {
+    (0..).find(|pause| {
+        !layers.iter().any(|layer| {
+            (layer.depth + pause) % (layer.range + layer.range - 2) == 0 
+        })
+    }).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    
+    #[test]
+    fn it_works_part1() {
+        let input = "0: 3
+1: 2
+4: 4
+6: 4";
+        assert_eq!(part1(&parse_input(input).unwrap()), 24)
+    }
+
+    #[test]
+    fn it_works_part2() {
+        let input = "0: 3
+1: 2
+4: 4
+6: 4";
+        assert_eq!(part2(&parse_input(input).unwrap()), 10)
+    }
+}
@@ -0,0 +1,163 @@
+use grid::{BitGrid, Grid};
+
+/// The disk as the puzzle draws it, `#` for used squares and `.` for free.
+pub fn to_ascii(disk: &BitGrid) -> String {
+    disk.render(|used| if used { '#' } else { '.' })
+}
+
+/// A colour for each region, spread round the hue circle by the golden ratio
+/// so neighbouring labels don't look alike.
+fn colour(label: usize) -> [u8; 3] {
+    let hue = (label as f64 * 0.618_033_988_75).fract() * 6.0;
+    let x = 1.0 - (hue % 2.0 - 1.0).abs();
+    let (r, g, b) = match hue as u8 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    // keep colours off black, which is free space
+    let channel = |c: f64| (64.0 + c * 191.0) as u8;
+    [channel(r), channel(g), channel(b)]
+}
+
+/// Every pixel row by row, each square drawn `scale` pixels wide.
+fn pixels(regions: &Grid<Option<usize>>, scale: usize) -> Vec<[u8; 3]> {
+    regions
+        .rows()
+        .flat_map(|row| {
+            let line = row
+                .iter()
+                .flat_map(|label| {
+                    let pixel = label.map_or([0, 0, 0], colour);
+                    std::iter::repeat_n(pixel, scale)
+                })
+                .collect::<Vec<_>>();
+            std::iter::repeat_n(line, scale).flatten()
+        })
+        .collect()
+}
+
+/// A binary PPM with each region in its own colour and free squares black.
+pub fn to_ppm(regions: &Grid<Option<usize>>, scale: usize) -> Vec<u8> {
+    let (width, height) = (regions.width() * scale, regions.height() * scale);
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    image.extend(pixels(regions, scale).into_iter().flatten());
+    image
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            if crc & 1 == 1 {
+                crc >> 1 ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    b << 16 | a
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// A PNG with the same colours as [`to_ppm`]. The image data is stored
+/// uncompressed, which keeps this free of dependencies and is small enough
+/// for a 128x128 disk.
+pub fn to_png(regions: &Grid<Option<usize>>, scale: usize) -> Vec<u8> {
+    let (width, height) = (regions.width() * scale, regions.height() * scale);
+    let pixels = pixels(regions, scale);
+
+    let mut raw = Vec::with_capacity(height * (1 + width * 3));
+    for row in pixels.chunks(width.max(1)).take(height) {
+        // each row starts with its filter type, 0 for none
+        raw.push(0);
+        raw.extend(row.iter().flatten());
+    }
+
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        zlib.push(last as u8);
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = vec![];
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bit RGB, default compression, filtering and no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib);
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn regions() -> Grid<Option<usize>> {
+        Grid::from_rows([[Some(0), None], [Some(1), Some(1)]])
+    }
+
+    #[test]
+    fn it_draws_ascii() {
+        let disk = BitGrid::from_rows([[true, false], [false, true]]);
+        assert_eq!(to_ascii(&disk), "#.\n.#\n");
+    }
+
+    #[test]
+    fn it_writes_ppm() {
+        let ppm = to_ppm(&regions(), 2);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 4 * 4 * 3);
+
+        let pixel = |x: usize, y: usize| {
+            let start = header.len() + (y * 4 + x) * 3;
+            [ppm[start], ppm[start + 1], ppm[start + 2]]
+        };
+        assert_eq!(pixel(1, 1), colour(0));
+        assert_eq!(pixel(2, 1), [0, 0, 0]);
+        assert_eq!(pixel(3, 3), colour(1));
+        assert_ne!(colour(0), colour(1));
+    }
+
+    #[test]
+    fn it_writes_png() {
+        let png = to_png(&regions(), 1);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 2]);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+}
@@ -0,0 +1,186 @@
+pub mod export;
+mod regions;
+mod strips;
+
+use common::{Answer, Error, Solution};
+use grid::{BitGrid, Point};
+use knothash::KnotHasher;
+use rayon::prelude::*;
+pub use regions::{Connectivity, Region, Regions};
+use std::io::Write;
+pub use strips::count_regions_parallel;
+
+pub struct Day14(String);
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self(input.trim().to_string()))
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(part1(&self.0).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(part2(&self.0).into())
+    }
+}
+
+/// Hashes `"{input}-{row}"`, sharing the key between rows.
+fn row_hash(key: &KnotHasher, row: usize) -> [u8; 16] {
+    let mut hasher = key.clone();
+    write!(hasher, "{}", row).expect("writing to a hasher can't fail");
+    hasher.finalize()
+}
+
+fn key_hasher(input: &str) -> KnotHasher {
+    let mut key = KnotHasher::new();
+    key.update(input).update("-");
+    key
+}
+
+pub fn part1(input: &str) -> u32 {
+    let key = key_hasher(input);
+    (0..128)
+        .into_par_iter()
+        .map(|i| {
+            row_hash(&key, i)
+                .iter()
+                .map(|byte| byte.count_ones())
+                .sum::<u32>()
+        })
+        .sum::<u32>()
+}
+
+/// The used squares, one row per hash.
+pub fn disk(input: &str) -> BitGrid {
+    disk_rows(input, 128)
+}
+
+/// A disk of any height, carrying on the puzzle's row numbering. The rows
+/// are hashed in parallel.
+pub fn disk_rows(input: &str, rows: usize) -> BitGrid {
+    let key = key_hasher(input);
+    let hashes = (0..rows)
+        .into_par_iter()
+        .map(|y| row_hash(&key, y))
+        .collect::<Vec<_>>();
+
+    let mut disk = BitGrid::new(128, rows);
+    for (y, hash) in hashes.iter().enumerate() {
+        for (x, used) in knothash::bits(hash).enumerate() {
+            disk.set(Point::new(x as i32, y as i32), used);
+        }
+    }
+    disk
+}
+
+pub fn part2(input: &str) -> u32 {
+    count_regions_parallel(&disk(input), Connectivity::Four) as u32
+}
+
+/// Part 2 over a disk of `rows` rows, hashed and counted on its own pool of
+/// `threads` threads, 0 meaning one per CPU.
+pub fn part2_with(input: &str, rows: usize, threads: usize) -> u32 {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("failed to start thread pool");
+    pool.install(|| count_regions_parallel(&disk_rows(input, rows), Connectivity::Four) as u32)
+}
+
+/// How many regions the used squares form, without labelling them.
+pub fn count_regions(disk: &BitGrid, connectivity: Connectivity) -> usize {
+    let mut seen = BitGrid::new(disk.width(), disk.height());
+    let mut count = 0;
+    for start in disk.ones() {
+        if !seen.get(start) {
+            regions::flood_fill(disk, &mut seen, start, connectivity, |_| {});
+            count += 1;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn it_works() {
+        assert_eq!(part1("flqrgnkx"), 8108)
+    }
+
+    #[test]
+    fn row_hash_works() {
+        let key = key_hasher("flqrgnkx");
+        assert_eq!(row_hash(&key, 12), knothash::hash(b"flqrgnkx-12"));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2("flqrgnkx"), 1242)
+    }
+
+    #[test]
+    fn part2_works_on_any_number_of_threads() {
+        for threads in [1, 3, 8] {
+            assert_eq!(part2_with("flqrgnkx", 128, threads), 1242);
+        }
+        let tall = disk_rows("flqrgnkx", 1000);
+        assert_eq!(
+            part2_with("flqrgnkx", 1000, 4) as usize,
+            count_regions(&tall, Connectivity::Four)
+        );
+    }
+
+    #[test]
+    fn it_counts_regions_at_the_edges() {
+        let full = BitGrid::from_rows(vec![vec![true; 128]; 128]);
+        assert_eq!(count_regions(&full, Connectivity::Four), 1);
+        assert_eq!(
+            count_regions(&BitGrid::new(128, 128), Connectivity::Four),
+            0
+        );
+
+        // the last column and bottom row mustn't wrap onto the first
+        let mut disk = BitGrid::new(128, 128);
+        for point in [(127, 0), (0, 1), (0, 127), (127, 127)] {
+            disk.set(Point::new(point.0, point.1), true);
+        }
+        assert_eq!(count_regions(&disk, Connectivity::Four), 4);
+        assert_eq!(count_regions(&disk, Connectivity::Eight), 4);
+    }
+
+    #[test]
+    fn it_draws_the_example() {
+        let ascii = export::to_ascii(&disk("flqrgnkx"));
+        let corner = ascii
+            .lines()
+            .take(8)
+            .map(|line| &line[..8])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            corner,
+            vec![
+                "##.#.#..", ".#.#.#.#", "....#.#.", "#.#.##.#", ".##.#...", "##..#..#", ".#...#..",
+                "##.#.##.",
+            ]
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn it_agrees_with_day10(key in "[a-z0-9 ,-]{0,16}", row in 0..128usize) {
+            let hash = row_hash(&key_hasher(&key), row);
+            let day10 = day10::part2(&format!("{}-{}", key, row));
+            proptest::prop_assert_eq!(knothash::to_hex(&hash), day10);
+        }
+
+        #[test]
+        fn it_agrees_with_the_reference(key in "[a-z0-9 ,-]{0,16}", row in 0..128usize) {
+            let hash = row_hash(&key_hasher(&key), row);
+            let reference = knothash::reference::hash(format!("{}-{}", key, row).as_bytes());
+            proptest::prop_assert_eq!(hash, reference);
+        }
+    }
+}
@@ -1,108 +1,104 @@
-mod knot_hash;
-use knot_hash::knot_hash;
-use rayon::prelude::*;
-
-fn part1(input: &str) -> u32 {
-    (0..128)
-        .into_par_iter()
-        .map(|i| {
-            knot_hash(&format!("{}-{}", input, i))
-                .iter()
-                .map(|byte| byte.count_ones())
-                .sum::<u32>()
-        })
-        .sum::<u32>()
+use common::input::{InputArgs, USAGE};
+use common::Solution;
+use day14::{export, Connectivity, Day14};
+use std::path::{Path, PathBuf};
+
+/// Extra output besides the answers: the disk as ASCII art on stdout, and
+/// its regions as a `.png` or `.ppm` image, optionally joined diagonally.
+#[derive(Debug, Default)]
+struct Export {
+    ascii: bool,
+    image: Option<PathBuf>,
+    scale: usize,
+    connectivity: Connectivity,
 }
 
-fn hash_as_vec_bool(input: &str, i: i32) -> Vec<bool> {
-    knot_hash(&format!("{}-{}", input, i)).into_iter().flat_map(|mut num| {
-        let mask = 1;
-        let mut result = vec![false; 8];
-
-        for i in 0..8 {
-            result[7 - i] = mask & num == 1;
-            num >>= 1;
+fn parse_args() -> Result<(InputArgs, Export), common::Error> {
+    let mut input_args = InputArgs::default();
+    let mut export = Export {
+        scale: 4,
+        ..Export::default()
+    };
+    let mut args = std::env::args().skip(1);
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| common::Error::InputError(format!("{} needs a value", flag)))
+        };
+        match flag.as_str() {
+            "--ascii" => export.ascii = true,
+            "--diagonal" => export.connectivity = Connectivity::Eight,
+            "--threads" => {
+                let threads = value()?
+                    .parse()
+                    .map_err(|_| common::Error::InputError("--threads must be a number".into()))?;
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build_global()
+                    .map_err(|e| common::Error::InputError(e.to_string()))?;
+            }
+            "--image" => export.image = Some(value()?.into()),
+            "--scale" => {
+                export.scale = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&scale| scale > 0)
+                    .ok_or_else(|| {
+                        common::Error::InputError("--scale must be a positive number".into())
+                    })?
+            }
+            _ => {
+                if !input_args
+                    .parse_flag(&flag, &mut args)
+                    .map_err(common::Error::InputError)?
+                {
+                    return Err(common::Error::InputError(format!(
+                        "unknown flag {:?}\nusage: day14 {} [--ascii] [--image <path.png|path.ppm>] [--scale <n>] [--diagonal] [--threads <n>]",
+                        flag, USAGE
+                    )));
+                }
+            }
         }
+    }
 
-        result
-    }).collect()
-}
-
-fn part2(input: &str) -> u32 {
-    let map : Vec<Vec<bool>> = (0..128)
-        .into_iter()
-        .map(|i| {
-            hash_as_vec_bool(input, i)
-        }).collect();
-
-    group_cells(&map)
+    Ok((input_args, export))
 }
 
-const DIRECTION_OFFSET: [(isize, isize); 4] = [
-    (0, -1),
-(-1, 0), (1, 0),
-    (0, 1)
-];
-
-fn get_cell(x: usize, y: usize, map: &[Vec<bool>]) -> Option<&bool> {
-    map.get(y)?.get(x)
+fn write_image(path: &Path, disk: &grid::BitGrid, export: &Export) -> Result<(), common::Error> {
+    let regions = day14::Regions::label(disk, export.connectivity);
+    let scale = export.scale;
+    let image = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("png") => export::to_png(regions.labels(), scale),
+        Some("ppm") => export::to_ppm(regions.labels(), scale),
+        _ => {
+            return Err(common::Error::InputError(format!(
+                "{}: images must be .png or .ppm",
+                path.display()
+            )))
+        }
+    };
+    std::fs::write(path, image)
+        .map_err(|e| common::Error::InputError(format!("{}: {}", path.display(), e)))
 }
 
-fn group_cells(map: &[Vec<bool>]) -> u32 {
-    let mut seen = std::collections::HashSet::new();
-    let mut groups_count = 0;
-    for (y, line) in map.iter().enumerate() {
-        for (x, cell) in line.iter().enumerate() {
-            if !seen.insert((x,y)) { continue}
-            if !cell { continue }
-
-            groups_count += 1;
+fn main() -> Result<(), common::Error> {
+    let (input_args, export) = parse_args()?;
+    let input = input_args.source(14).read()?;
+    let day = Day14::parse(&input)?;
 
-            let mut local_seen = std::collections::HashSet::new();
-            let mut candidates_list: std::collections::VecDeque<(usize, usize)> = std::collections::VecDeque::new();
-            candidates_list.push_front((x, y));
+    println!("part1: {}", day.part1()?);
+    println!("part2: {}", day.part2()?);
 
-            while let Some((x,y)) = candidates_list.pop_back() {
-                if !local_seen.insert((x,y)) { continue}
-                if let Some(true) = get_cell(x, y, map) {
-                    DIRECTION_OFFSET.iter().filter_map(|(offset_x, offset_y)| {
-                        let x = x as isize + offset_x;
-                        let y = y as isize + offset_y;
-                        (x.is_positive()  || y.is_positive()).then(|| {
-                            (x as usize, y as usize)
-                        })
-                    }).for_each(|coord| candidates_list.push_front(coord));
-                } else { continue};
-            }
-
-            seen.extend(local_seen)
+    if export.ascii || export.image.is_some() {
+        let disk = day14::disk(input.trim());
+        if export.ascii {
+            print!("{}", export::to_ascii(&disk));
+        }
+        if let Some(path) = &export.image {
+            write_image(path, &disk, &export)?;
         }
     }
 
-    groups_count
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[test]
-    fn it_works() {
-        assert_eq!(part1("flqrgnkx"), 8108)
-    }
-
-    // #[test]
-    // fn into_vec_bool() {
-    //     assert_eq!(hash_as_vec_bool("flqrgnkx", 0), vec![]);
-    // }
-    #[test]
-    fn part2_works() {
-        assert_eq!(part2("flqrgnkx"), 1242)
-    }
-}
-
-fn main() {
-    let input = "jzgqcdpd";
-
-    println!("part1: {}", part1(input));
-    println!("part1: {}", part2(input));
+    Ok(())
 }
@@ -0,0 +1,282 @@
+use grid::{BitGrid, Grid, Point};
+
+/// Which used squares count as adjacent when grouping them into regions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right, as the puzzle has it.
+    #[default]
+    Four,
+    /// Diagonals too.
+    Eight,
+}
+
+impl Connectivity {
+    fn offsets(self) -> &'static [Point] {
+        match self {
+            Connectivity::Four => &Point::ORTHOGONAL,
+            Connectivity::Eight => &Point::SURROUNDING,
+        }
+    }
+}
+
+/// Visits every used square reachable from `start` that isn't yet `seen`,
+/// marking each one as it goes.
+pub(crate) fn flood_fill(
+    disk: &BitGrid,
+    seen: &mut BitGrid,
+    start: Point,
+    connectivity: Connectivity,
+    mut visit: impl FnMut(Point),
+) {
+    if !disk.get(start) || seen.get(start) {
+        return;
+    }
+    seen.set(start, true);
+    let mut stack = vec![start];
+    while let Some(point) = stack.pop() {
+        visit(point);
+        for &offset in connectivity.offsets() {
+            let neighbour = point + offset;
+            // out of bounds reads as free, so the edges need no special case
+            if disk.get(neighbour) && !seen.get(neighbour) {
+                seen.set(neighbour, true);
+                stack.push(neighbour);
+            }
+        }
+    }
+}
+
+/// One group of connected used squares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    label: usize,
+    cells: Vec<Point>,
+    top_left: Point,
+    bottom_right: Point,
+    perimeter: usize,
+}
+
+impl Region {
+    pub fn label(&self) -> usize {
+        self.label
+    }
+
+    /// The region's squares, row by row.
+    pub fn cells(&self) -> &[Point] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The top left and bottom right squares of the smallest box holding the
+    /// region.
+    pub fn bounding_box(&self) -> (Point, Point) {
+        (self.top_left, self.bottom_right)
+    }
+
+    /// How many square edges border something outside the region: a free
+    /// square, another region or the edge of the disk. Only the four sides
+    /// count, whatever the connectivity.
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+}
+
+/// Every region of a disk, labelled in the order their first square comes
+/// row by row.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn label(disk: &BitGrid, connectivity: Connectivity) -> Self {
+        let mut labels = Grid::new(disk.width(), disk.height(), None);
+        let mut seen = BitGrid::new(disk.width(), disk.height());
+        let mut cells = vec![];
+        for start in disk.ones() {
+            if seen.get(start) {
+                continue;
+            }
+            let label = cells.len();
+            let mut region = vec![];
+            flood_fill(disk, &mut seen, start, connectivity, |point| {
+                labels.set(point, Some(label));
+                region.push(point);
+            });
+            region.sort_by_key(|point| (point.y, point.x));
+            cells.push(region);
+        }
+
+        let regions = cells
+            .into_iter()
+            .enumerate()
+            .map(|(label, cells)| {
+                let perimeter = cells
+                    .iter()
+                    .flat_map(|point| point.neighbours4())
+                    .filter(|&neighbour| labels.get(neighbour) != Some(&Some(label)))
+                    .count();
+                let top_left = Point::new(
+                    cells.iter().map(|point| point.x).min().unwrap_or_default(),
+                    cells.iter().map(|point| point.y).min().unwrap_or_default(),
+                );
+                let bottom_right = Point::new(
+                    cells.iter().map(|point| point.x).max().unwrap_or_default(),
+                    cells.iter().map(|point| point.y).max().unwrap_or_default(),
+                );
+                Region {
+                    label,
+                    cells,
+                    top_left,
+                    bottom_right,
+                    perimeter,
+                }
+            })
+            .collect();
+
+        Regions { labels, regions }
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+
+    pub fn get(&self, label: usize) -> Option<&Region> {
+        self.regions.get(label)
+    }
+
+    /// Each square's region label, `None` for free squares.
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    /// The region holding `point`, if it's a used square on the disk.
+    pub fn region_at(&self, point: Point) -> Option<&Region> {
+        let label = (*self.labels.get(point)?)?;
+        self.get(label)
+    }
+
+    /// The region with the most squares, the first one found on a tie.
+    pub fn largest(&self) -> Option<&Region> {
+        self.regions.iter().rev().max_by_key(|region| region.area())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn regions() -> Regions {
+        Regions::label(
+            &BitGrid::from_rows([
+                [true, true, false, false],
+                [false, true, false, true],
+                [true, false, false, true],
+            ]),
+            Connectivity::Four,
+        )
+    }
+
+    #[test]
+    fn it_labels_regions() {
+        let regions = regions();
+        assert_eq!(regions.len(), 3);
+        assert_eq!(
+            regions.labels().rows().collect::<Vec<_>>(),
+            vec![
+                &[Some(0), Some(0), None, None],
+                &[None, Some(0), None, Some(1)],
+                &[Some(2), None, None, Some(1)],
+            ]
+        );
+    }
+
+    #[test]
+    fn it_measures_regions() {
+        let regions = regions();
+        let region = regions.get(0).unwrap();
+        assert_eq!(
+            region.cells(),
+            &[Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)]
+        );
+        assert_eq!(region.area(), 3);
+        assert_eq!(region.bounding_box(), (Point::new(0, 0), Point::new(1, 1)));
+        assert_eq!(region.perimeter(), 8);
+
+        let region = regions.get(1).unwrap();
+        assert_eq!(region.perimeter(), 6);
+        assert_eq!(region.bounding_box(), (Point::new(3, 1), Point::new(3, 2)));
+        assert_eq!(regions.get(2).unwrap().perimeter(), 4);
+    }
+
+    #[test]
+    fn it_answers_queries() {
+        let regions = regions();
+        assert_eq!(regions.region_at(Point::new(3, 2)).unwrap().label(), 1);
+        assert_eq!(regions.region_at(Point::new(2, 2)), None);
+        assert_eq!(regions.region_at(Point::new(-1, 0)), None);
+        assert_eq!(regions.largest().unwrap().label(), 0);
+
+        let tied = Regions::label(
+            &BitGrid::from_rows([[true, false, true]]),
+            Connectivity::Four,
+        );
+        assert_eq!(tied.largest().unwrap().label(), 0);
+        assert!(
+            Regions::label(&BitGrid::from_rows([[false]]), Connectivity::Four)
+                .largest()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn it_joins_diagonals_when_asked() {
+        let disk = BitGrid::from_rows([[true, false, true], [false, true, false]]);
+        assert_eq!(Regions::label(&disk, Connectivity::Four).len(), 3);
+
+        let regions = Regions::label(&disk, Connectivity::Eight);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions.get(0).unwrap().area(), 3);
+        assert_eq!(regions.get(0).unwrap().perimeter(), 12);
+    }
+
+    #[test]
+    fn it_labels_edges_and_corners() {
+        // a ring touching every edge and corner of the disk
+        let disk = BitGrid::from_rows([
+            [true, true, true, true],
+            [true, false, false, true],
+            [true, true, true, true],
+        ]);
+        let regions = Regions::label(&disk, Connectivity::Four);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions.get(0).unwrap().area(), 10);
+        assert_eq!(
+            regions.get(0).unwrap().bounding_box(),
+            (Point::new(0, 0), Point::new(3, 2))
+        );
+
+        let corners = BitGrid::from_rows([
+            [true, false, true],
+            [false, false, false],
+            [true, false, true],
+        ]);
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            let regions = Regions::label(&corners, connectivity);
+            assert_eq!(regions.len(), 4);
+            assert!(regions.iter().all(|region| region.perimeter() == 4));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::Connectivity;
+use graph::UnionFind;
+use grid::{BitGrid, Point};
+use rayon::prelude::*;
+
+/// The regions of one strip of rows, counted as if nothing lay outside it.
+struct Strip {
+    count: usize,
+    /// Each square's region in the strip's first and last rows, numbered
+    /// `0..count`.
+    top: Vec<Option<usize>>,
+    bottom: Vec<Option<usize>>,
+}
+
+/// The squares in earlier positions that `connectivity` joins to a square,
+/// so each pair is only looked at once.
+fn behind(connectivity: Connectivity) -> &'static [Point] {
+    const FOUR: [Point; 2] = [Point::LEFT, Point::UP];
+    const EIGHT: [Point; 4] = [
+        Point::LEFT,
+        Point::new(-1, -1),
+        Point::UP,
+        Point::new(1, -1),
+    ];
+    match connectivity {
+        Connectivity::Four => &FOUR,
+        Connectivity::Eight => &EIGHT,
+    }
+}
+
+fn label_strip(disk: &BitGrid, rows: std::ops::Range<usize>, connectivity: Connectivity) -> Strip {
+    let width = disk.width();
+    let first = rows.start as i32;
+    let index = |point: Point| (point.y - first) as usize * width + point.x as usize;
+    let in_strip = |point: Point| point.y >= first && disk.get(point);
+
+    let mut sets = UnionFind::new(width * rows.len());
+    for y in rows.clone() {
+        for x in 0..width {
+            let point = Point::new(x as i32, y as i32);
+            if !disk.get(point) {
+                continue;
+            }
+            for &offset in behind(connectivity) {
+                let neighbour = point + offset;
+                if in_strip(neighbour) {
+                    sets.union(index(point), index(neighbour));
+                }
+            }
+        }
+    }
+
+    // number the regions in the order their first square comes
+    let mut numbers = vec![None; sets.len()];
+    let mut count = 0;
+    let mut labels = vec![None; sets.len()];
+    for (i, label) in labels.iter_mut().enumerate() {
+        if disk.get(Point::new((i % width) as i32, first + (i / width) as i32)) {
+            let root = sets.find(i);
+            *label = Some(*numbers[root].get_or_insert_with(|| {
+                count += 1;
+                count - 1
+            }));
+        }
+    }
+    let top = labels[..width].to_vec();
+    let bottom = labels[labels.len() - width..].to_vec();
+
+    Strip { count, top, bottom }
+}
+
+/// Counts regions by labelling `strips` bands of rows in parallel, then
+/// joining the regions that meet across each boundary.
+fn count_in_strips(disk: &BitGrid, connectivity: Connectivity, strips: usize) -> usize {
+    let height = disk.height();
+    if height == 0 || disk.width() == 0 {
+        return 0;
+    }
+    let strip_height = height.div_ceil(strips.clamp(1, height));
+    let strips = (0..height)
+        .step_by(strip_height)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|start| {
+            label_strip(
+                disk,
+                start..(start + strip_height).min(height),
+                connectivity,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut offsets = Vec::with_capacity(strips.len());
+    let mut total = 0;
+    for strip in &strips {
+        offsets.push(total);
+        total += strip.count;
+    }
+
+    let reach: &[i32] = match connectivity {
+        Connectivity::Four => &[0],
+        Connectivity::Eight => &[-1, 0, 1],
+    };
+    let mut regions = UnionFind::new(total);
+    for (i, pair) in strips.windows(2).enumerate() {
+        let (above, below) = (&pair[0].bottom, &pair[1].top);
+        for (x, region) in above.iter().enumerate() {
+            let Some(region) = region else { continue };
+            for &dx in reach {
+                let Some(Some(other)) = (x as i32 + dx)
+                    .try_into()
+                    .ok()
+                    .and_then(|x: usize| below.get(x))
+                else {
+                    continue;
+                };
+                regions.union(offsets[i] + region, offsets[i + 1] + other);
+            }
+        }
+    }
+    regions.sets()
+}
+
+/// Like [`crate::count_regions`], but split into one strip of rows per
+/// thread in the current rayon pool.
+pub fn count_regions_parallel(disk: &BitGrid, connectivity: Connectivity) -> usize {
+    count_in_strips(disk, connectivity, rayon::current_num_threads())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::count_regions;
+
+    #[test]
+    fn it_joins_strips() {
+        // a U shape, only joined in the bottom strip
+        let disk =
+            BitGrid::from_rows([[true, false, true], [true, false, true], [true, true, true]]);
+        for strips in 1..=3 {
+            assert_eq!(count_in_strips(&disk, Connectivity::Four, strips), 1);
+        }
+
+        let diagonal = BitGrid::from_rows([[true, false], [false, true]]);
+        assert_eq!(count_in_strips(&diagonal, Connectivity::Four, 2), 2);
+        assert_eq!(count_in_strips(&diagonal, Connectivity::Eight, 2), 1);
+        assert_eq!(
+            count_in_strips(&BitGrid::new(0, 0), Connectivity::Four, 4),
+            0
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn it_agrees_with_flood_fill(
+            rows in proptest::collection::vec(proptest::collection::vec(proptest::bool::ANY, 9), 1..24),
+            strips in 1..30usize,
+            diagonal: bool,
+        ) {
+            let disk = BitGrid::from_rows(rows);
+            let connectivity = if diagonal { Connectivity::Eight } else { Connectivity::Four };
+            proptest::prop_assert_eq!(
+                count_in_strips(&disk, connectivity, strips),
+                count_regions(&disk, connectivity)
+            );
+        }
+    }
+}
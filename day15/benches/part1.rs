@@ -0,0 +1,52 @@
+//! Times part 1 with the general `%` modulo, the Mersenne-prime reduction
+//! and the reduction over 4 and 8 lanes at once, checking each agrees. Run
+//! with `cargo bench -p day15 --bench part1 [-- <pairs>]`.
+
+mod timing;
+
+use day15::{Generator, Judge, Lanes, FACTOR_A, FACTOR_B, MODULUS};
+use timing::time;
+
+const A: u64 = 65;
+const B: u64 = 8921;
+
+/// How part 1 used to step the generators, dividing every time.
+fn division(pairs: usize) -> usize {
+    let (mut a, mut b) = (A, B);
+    (0..pairs)
+        .filter(|_| {
+            a = std::hint::black_box(a * FACTOR_A) % MODULUS;
+            b = std::hint::black_box(b * FACTOR_B) % MODULUS;
+            (a ^ b) & 0xffff == 0
+        })
+        .count()
+}
+
+fn main() {
+    let pairs = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(day15::PART1_PAIRS);
+    let judge = Judge::new(pairs);
+
+    let mersenne = || judge.count(Generator::a(A), Generator::b(B));
+    let lanes4 = || judge.count_lanes(Lanes::<4>::new(FACTOR_A, A), Lanes::new(FACTOR_B, B));
+    let lanes8 = || judge.count_lanes(Lanes::<8>::new(FACTOR_A, A), Lanes::new(FACTOR_B, B));
+    let expected = division(pairs);
+    assert_eq!(mersenne(), expected);
+    assert_eq!(lanes4(), expected);
+    assert_eq!(lanes8(), expected);
+
+    let division = time("division", pairs, || division(pairs));
+    for (name, count) in [
+        ("mersenne", &mersenne as &dyn Fn() -> usize),
+        ("4 lanes", &lanes4),
+        ("8 lanes", &lanes8),
+    ] {
+        let median = time(name, pairs, count);
+        println!(
+            "  speedup  {:.2}x",
+            division.as_secs_f64() / median.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Times part 2 three ways: the old unbounded channel per generator, both
+//! generators in lockstep on one thread, and bounded batches between
+//! threads. Run with `cargo bench -p day15 [-- <pairs>]`.
+
+use std::sync::mpsc;
+use std::thread;
+
+mod timing;
+
+use day15::{Generator, Judge};
+use timing::time;
+
+const A: u64 = 65;
+const B: u64 = 8921;
+
+/// How part 2 used to work: every value sent on its own down an unbounded
+/// channel.
+fn channel(pairs: usize) -> usize {
+    let (tx_a, rx_a) = mpsc::channel();
+    let (tx_b, rx_b) = mpsc::channel();
+    let send_all = |tx: mpsc::Sender<u64>, values: &mut dyn Iterator<Item = u64>| {
+        for value in values.take(pairs) {
+            tx.send(value).expect("couldn't send");
+        }
+    };
+    thread::scope(|scope| {
+        scope.spawn(|| send_all(tx_a, &mut Generator::a(A).multiples_of(4)));
+        scope.spawn(|| send_all(tx_b, &mut Generator::b(B).multiples_of(8)));
+        Judge::new(pairs).count(rx_a, rx_b)
+    })
+}
+
+fn main() {
+    let pairs = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(day15::PART2_PAIRS);
+
+    let expected = day15::part2_pairs(A, B, pairs);
+    assert_eq!(channel(pairs), expected);
+
+    let channel = time("channel", pairs, || channel(pairs));
+    let lockstep = time("lockstep", pairs, || day15::part2_pairs(A, B, pairs));
+    for batch in [256, 4096, 65536] {
+        let judge = Judge::new(pairs);
+        let count = || {
+            judge.count_batched(
+                Generator::a(A).multiples_of(4),
+                Generator::b(B).multiples_of(8),
+                batch,
+            )
+        };
+        assert_eq!(count(), expected);
+        let batched = time(&format!("batched {}", batch), pairs, count);
+        println!(
+            "  vs channel   {:.2}x",
+            channel.as_secs_f64() / batched.as_secs_f64()
+        );
+    }
+    println!(
+        "lockstep vs channel {:.2}x",
+        channel.as_secs_f64() / lockstep.as_secs_f64()
+    );
+}
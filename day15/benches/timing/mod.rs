@@ -0,0 +1,25 @@
+//! The timer both benches share.
+
+use std::time::{Duration, Instant};
+
+/// Runs `count` five times and prints the median time, per pair too.
+pub fn time(name: &str, pairs: usize, count: impl Fn() -> usize) -> Duration {
+    let mut samples = (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(count());
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    samples.sort();
+
+    let median = samples[samples.len() / 2];
+    println!(
+        "{:<14} {:>9} pairs in {:>10.2?}  {:>6.2} ns/pair",
+        name,
+        pairs,
+        median,
+        median.as_nanos() as f64 / pairs as f64
+    );
+    median
+}
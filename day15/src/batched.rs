@@ -0,0 +1,62 @@
+use std::sync::mpsc;
+use std::thread;
+
+use crate::Judge;
+
+/// Sends `values` on in batches of `batch`, stopping at `limit` values or
+/// when the other end hangs up.
+fn send_batches(
+    tx: mpsc::SyncSender<Vec<u64>>,
+    values: impl Iterator<Item = u64>,
+    limit: usize,
+    batch: usize,
+) {
+    let mut values = values.take(limit);
+    loop {
+        let next = values.by_ref().take(batch).collect::<Vec<_>>();
+        if next.is_empty() || tx.send(next).is_err() {
+            return;
+        }
+    }
+}
+
+impl Judge {
+    /// Like [`Judge::count`], but runs each generator on its own thread and
+    /// hands values over `batch` at a time. At most two batches from each
+    /// generator wait to be judged, so memory stays bounded however many
+    /// pairs there are.
+    pub fn count_batched<A, B>(&self, a: A, b: B, batch: usize) -> usize
+    where
+        A: Iterator<Item = u64> + Send,
+        B: Iterator<Item = u64> + Send,
+    {
+        assert!(batch > 0, "batches must hold at least one value");
+        let (tx_a, rx_a) = mpsc::sync_channel(2);
+        let (tx_b, rx_b) = mpsc::sync_channel(2);
+
+        thread::scope(|scope| {
+            scope.spawn(|| send_batches(tx_a, a, self.pairs, batch));
+            scope.spawn(|| send_batches(tx_b, b, self.pairs, batch));
+            self.count(rx_a.into_iter().flatten(), rx_b.into_iter().flatten())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Generator;
+
+    #[test]
+    fn it_matches_lockstep() {
+        let a = Generator::a(65).multiples_of(4);
+        let b = Generator::b(8921).multiples_of(8);
+        for (pairs, batch) in [(0, 1), (1056, 1), (1056, 1000), (20_000, 7), (20_000, 4096)] {
+            let judge = Judge::new(pairs);
+            assert_eq!(
+                judge.count_batched(a.clone(), b.clone(), batch),
+                judge.count(a.clone(), b.clone())
+            );
+        }
+    }
+}
@@ -0,0 +1,300 @@
+/// The puzzle's modulus, the Mersenne prime 2^31 - 1.
+pub const MODULUS: u64 = 2_147_483_647;
+pub const FACTOR_A: u64 = 16807;
+pub const FACTOR_B: u64 = 48271;
+
+/// `product % MODULUS` without dividing, for the product of two values
+/// below `MODULUS`: since 2^31 is 1 modulo 2^31 - 1, the bits above 31 can
+/// be folded back onto the low ones.
+pub(crate) fn reduce(product: u64) -> u64 {
+    debug_assert!(product < MODULUS * MODULUS);
+    let folded = (product & MODULUS) + (product >> 31);
+    if folded >= MODULUS {
+        folded - MODULUS
+    } else {
+        folded
+    }
+}
+
+/// `base` to the power `exponent` modulo `modulus`, by repeated squaring.
+pub(crate) fn pow_mod(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let multiply = |a: u64, b: u64| (a as u128 * b as u128 % modulus as u128) as u64;
+    let (mut base, mut exponent, mut power) = (base % modulus, exponent, 1 % modulus);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            power = multiply(power, base);
+        }
+        base = multiply(base, base);
+        exponent >>= 1;
+    }
+    power
+}
+
+fn any(_: u64) -> bool {
+    true
+}
+
+/// A multiplicative generator: each value is the previous one times `factor`,
+/// modulo `modulus`. Values the acceptance predicate rejects are skipped.
+///
+/// The iterator never ends, so a predicate that rejects every value hangs.
+#[derive(Debug, Clone)]
+pub struct Generator<F = fn(u64) -> bool> {
+    value: u64,
+    factor: u64,
+    modulus: u64,
+    /// Whether every product fits [`reduce`], so no division is needed.
+    mersenne: bool,
+    accept: F,
+}
+
+fn fits_mersenne(value: u64, factor: u64, modulus: u64) -> bool {
+    modulus == MODULUS && value < MODULUS && factor < MODULUS
+}
+
+impl Generator {
+    /// A generator accepting every value, using the puzzle's modulus.
+    pub fn new(factor: u64, start: u64) -> Self {
+        Generator {
+            value: start,
+            factor,
+            modulus: MODULUS,
+            mersenne: fits_mersenne(start, factor, MODULUS),
+            accept: any,
+        }
+    }
+
+    /// Generator A from the puzzle.
+    pub fn a(start: u64) -> Self {
+        Generator::new(FACTOR_A, start)
+    }
+
+    /// Generator B from the puzzle.
+    pub fn b(start: u64) -> Self {
+        Generator::new(FACTOR_B, start)
+    }
+}
+
+impl<F> Generator<F> {
+    /// Swaps the modulus.
+    pub fn with_modulus(self, modulus: u64) -> Self {
+        assert!(modulus > 0, "the modulus must be positive");
+        Generator {
+            modulus,
+            mersenne: fits_mersenne(self.value, self.factor, modulus),
+            ..self
+        }
+    }
+
+    /// Only yields values `accept` returns true for.
+    pub fn accepting<G: Fn(u64) -> bool>(self, accept: G) -> Generator<G> {
+        Generator {
+            value: self.value,
+            factor: self.factor,
+            modulus: self.modulus,
+            mersenne: self.mersenne,
+            accept,
+        }
+    }
+
+    /// Only yields multiples of `n`, as in part 2.
+    pub fn multiples_of(self, n: u64) -> Generator<impl Fn(u64) -> bool + Clone> {
+        assert!(n > 0, "there are no multiples of 0 to accept");
+        self.accepting(move |value| value % n == 0)
+    }
+
+    /// Moves on `steps` values at once, multiplying by the factor to the
+    /// `steps`th power, in O(log steps). Rejected values count as steps too,
+    /// so for a picky generator this jumps through the raw sequence.
+    pub fn jump(&mut self, steps: u64) {
+        let multiplier = pow_mod(self.factor, steps, self.modulus);
+        self.value = (self.value as u128 * multiplier as u128 % self.modulus as u128) as u64;
+        self.mersenne = fits_mersenne(self.value, self.factor, self.modulus);
+    }
+
+    /// The last value generated, or the start value.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl<F: Fn(u64) -> bool> Iterator for Generator<F> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            self.value = if self.mersenne {
+                reduce(self.value * self.factor)
+            } else {
+                // any other modulus, value or factor may not fit in a u64
+                (self.value as u128 * self.factor as u128 % self.modulus as u128) as u64
+            };
+            if (self.accept)(self.value) {
+                return Some(self.value);
+            }
+        }
+    }
+}
+
+/// Compares two generators' values pair by pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Judge {
+    pub pairs: usize,
+    /// The bits that have to match, the lowest 16 in the puzzle.
+    pub mask: u64,
+}
+
+impl Judge {
+    pub fn new(pairs: usize) -> Self {
+        Judge {
+            pairs,
+            mask: 0xffff,
+        }
+    }
+
+    pub fn with_mask(self, mask: u64) -> Self {
+        Judge { mask, ..self }
+    }
+
+    /// How many of the first `pairs` pairs agree on the masked bits.
+    pub fn count(
+        &self,
+        a: impl IntoIterator<Item = u64>,
+        b: impl IntoIterator<Item = u64>,
+    ) -> usize {
+        a.into_iter()
+            .zip(b)
+            .take(self.pairs)
+            .filter(|(a, b)| (a ^ b) & self.mask == 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_generates_the_example() {
+        assert_eq!(
+            Generator::a(65).take(5).collect::<Vec<_>>(),
+            vec![1092455, 1181022009, 245556042, 1744312007, 1352636452]
+        );
+        assert_eq!(
+            Generator::b(8921).take(5).collect::<Vec<_>>(),
+            vec![430625591, 1233683848, 1431495498, 137874439, 285222916]
+        );
+    }
+
+    #[test]
+    fn it_reduces_without_dividing() {
+        let largest = (MODULUS - 1) * (MODULUS - 1);
+        for product in [
+            0,
+            1,
+            MODULUS - 1,
+            MODULUS,
+            2 * MODULUS,
+            largest - 1,
+            largest,
+        ] {
+            assert_eq!(reduce(product), product % MODULUS, "{}", product);
+        }
+
+        let (mut a, mut b) = (65, 8921);
+        for (fast_a, fast_b) in Generator::a(65).zip(Generator::b(8921)).take(100_000) {
+            a = a * FACTOR_A % MODULUS;
+            b = b * FACTOR_B % MODULUS;
+            assert_eq!((fast_a, fast_b), (a, b));
+        }
+    }
+
+    #[test]
+    fn it_filters_values() {
+        assert_eq!(
+            Generator::a(65).multiples_of(4).take(5).collect::<Vec<_>>(),
+            vec![1352636452, 1992081072, 530830436, 1980017072, 740335192]
+        );
+        assert_eq!(
+            Generator::b(8921)
+                .multiples_of(8)
+                .take(5)
+                .collect::<Vec<_>>(),
+            vec![1233683848, 862516352, 1159784568, 1616057672, 412269392]
+        );
+        let big = Generator::new(MODULUS + FACTOR_A, MODULUS + 65);
+        assert_eq!(
+            big.take(3).collect::<Vec<_>>(),
+            vec![1092455, 1181022009, 245556042]
+        );
+        let odd = Generator::new(3, 1)
+            .with_modulus(11)
+            .accepting(|value| value % 2 == 1);
+        assert_eq!(odd.take(4).collect::<Vec<_>>(), vec![3, 9, 5, 1]);
+    }
+
+    #[test]
+    fn it_multiplies_past_u64() {
+        // 2^61 - 1 is prime, so every product of two values is above 2^64
+        let modulus = (1 << 61) - 1;
+        let mut huge = Generator::new(modulus - 1, modulus - 1).with_modulus(modulus);
+        // -1 times -1 is 1, and 1 times -1 is -1 again
+        assert_eq!(huge.next(), Some(1));
+        assert_eq!(huge.next(), Some(modulus - 1));
+
+        let mut wrapped = Generator::new(u64::MAX, u64::MAX);
+        let expected = (u64::MAX % MODULUS) * (u64::MAX % MODULUS) % MODULUS;
+        assert_eq!(wrapped.next(), Some(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "no multiples of 0")]
+    fn it_rejects_multiples_of_zero() {
+        Generator::a(65).multiples_of(0);
+    }
+
+    #[test]
+    fn it_jumps_ahead() {
+        let mut jumped = Generator::a(65);
+        jumped.jump(4);
+        assert_eq!(jumped.next(), Some(1352636452));
+
+        let mut stepped = Generator::b(8921);
+        let value = stepped.nth(999_999);
+        let mut jumped = Generator::b(8921);
+        jumped.jump(1_000_000);
+        assert_eq!(Some(jumped.value()), value);
+        assert_eq!(jumped.next(), stepped.next());
+
+        let mut small = Generator::new(3, 1).with_modulus(11);
+        small.jump(0);
+        assert_eq!(small.value(), 1);
+        small.jump(u64::MAX);
+        // 3 has order 5 modulo 11, and u64::MAX is 0 modulo 5
+        assert_eq!(small.value(), 1);
+
+        // jumping counts rejected values as well
+        let mut picky = Generator::a(65).multiples_of(4);
+        picky.jump(4);
+        assert_eq!(picky.value(), 1744312007);
+        assert_eq!(picky.next(), Some(1352636452));
+    }
+
+    #[test]
+    fn it_judges_pairs() {
+        let judge = Judge::new(5);
+        assert_eq!(judge.count(Generator::a(65), Generator::b(8921)), 1);
+        assert_eq!(
+            judge
+                .with_mask(0)
+                .count(Generator::a(65), Generator::b(8921)),
+            5
+        );
+
+        let judge = Judge::new(1056);
+        let a = Generator::a(65).multiples_of(4);
+        let b = Generator::b(8921).multiples_of(8);
+        assert_eq!(judge.count(a.clone(), b.clone()), 1);
+        assert_eq!(Judge::new(1055).count(a, b), 0);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::generator::{pow_mod, reduce, MODULUS};
+use crate::{thread_count, Judge};
+
+/// `N` consecutive values of an unfiltered generator with the puzzle's
+/// modulus, all advanced together so the arithmetic can be vectorised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lanes<const N: usize> {
+    values: [u64; N],
+    factor: u64,
+    /// The factor raised to the `N`th power, moving each lane on `N` places.
+    stride: u64,
+}
+
+impl<const N: usize> Lanes<N> {
+    /// Lanes whose first step gives the generator's first `N` values.
+    pub fn new(factor: u64, start: u64) -> Self {
+        const { assert!(N > 0, "there has to be at least one lane") };
+        let factor = factor % MODULUS;
+        let mut values = [0; N];
+        let mut value = start % MODULUS;
+        for slot in &mut values {
+            value = reduce(value * factor);
+            *slot = value;
+        }
+        let stride = pow_mod(factor, N as u64, MODULUS);
+        Lanes {
+            values,
+            factor,
+            stride,
+        }
+    }
+
+    /// Moves every lane on `steps` values, like [`crate::Generator::jump`].
+    pub fn jump(&mut self, steps: u64) {
+        let multiplier = pow_mod(self.factor, steps, MODULUS);
+        for value in &mut self.values {
+            *value = reduce(*value * multiplier);
+        }
+    }
+
+    /// The next `N` values, moving every lane on `N` places.
+    pub fn step(&mut self) -> [u64; N] {
+        let values = self.values;
+        for value in &mut self.values {
+            *value = reduce(*value * self.stride);
+        }
+        values
+    }
+}
+
+impl Judge {
+    /// Like [`Judge::count`] for two unfiltered generators, checking `N`
+    /// pairs at a time.
+    pub fn count_lanes<const N: usize>(&self, mut a: Lanes<N>, mut b: Lanes<N>) -> usize {
+        let mut count = 0;
+        for block in 0..self.pairs.div_ceil(N) {
+            let (a, b) = (a.step(), b.step());
+            let lanes = (self.pairs - block * N).min(N);
+            count += (0..lanes)
+                .filter(|&lane| (a[lane] ^ b[lane]) & self.mask == 0)
+                .count();
+        }
+        count
+    }
+}
+
+impl Judge {
+    /// Like [`Judge::count_lanes`], but splits the pairs into one range per
+    /// thread, 0 meaning one per CPU. Each thread jumps its lanes to the start
+    /// of its range, so the count is the same however many threads there are.
+    pub fn count_parallel<const N: usize>(
+        &self,
+        a: Lanes<N>,
+        b: Lanes<N>,
+        threads: usize,
+    ) -> usize {
+        let threads = thread_count(threads);
+        let chunk = self.pairs.div_ceil(threads).max(1);
+
+        std::thread::scope(|scope| {
+            let workers = (0..self.pairs)
+                .step_by(chunk)
+                .map(|start| {
+                    let judge = Judge {
+                        pairs: chunk.min(self.pairs - start),
+                        ..*self
+                    };
+                    let (mut a, mut b) = (a, b);
+                    scope.spawn(move || {
+                        a.jump(start as u64);
+                        b.jump(start as u64);
+                        judge.count_lanes(a, b)
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("judge thread panicked"))
+                .sum()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Generator, FACTOR_A, FACTOR_B};
+
+    #[test]
+    fn it_steps_like_a_generator() {
+        let mut lanes = Lanes::<4>::new(FACTOR_A, 65);
+        let values = (0..3).flat_map(|_| lanes.step()).collect::<Vec<_>>();
+        assert_eq!(values, Generator::a(65).take(12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_jumps_like_a_generator() {
+        let mut lanes = Lanes::<4>::new(FACTOR_B, 8921);
+        lanes.jump(1001);
+        let mut generator = Generator::b(8921);
+        generator.jump(1001);
+        assert_eq!(lanes.step().to_vec(), generator.take(4).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_splits_pairs_between_threads() {
+        let (a, b) = (Lanes::<4>::new(FACTOR_A, 65), Lanes::new(FACTOR_B, 8921));
+        for pairs in [0, 1, 7, 1056, 100_003] {
+            let judge = Judge::new(pairs).with_mask(0xff);
+            let serial = judge.count_lanes(a, b);
+            for threads in [0, 1, 2, 3, 8, 64] {
+                assert_eq!(
+                    judge.count_parallel(a, b, threads),
+                    serial,
+                    "{} pairs, {} threads",
+                    pairs,
+                    threads
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_judges_like_a_generator() {
+        for pairs in [0, 1, 3, 5, 1000, 1001, 1003] {
+            let judge = Judge::new(pairs).with_mask(0xf);
+            let serial = judge.count(Generator::a(65), Generator::b(8921));
+            assert_eq!(
+                judge.count_lanes(Lanes::<4>::new(FACTOR_A, 65), Lanes::new(FACTOR_B, 8921)),
+                serial
+            );
+            assert_eq!(
+                judge.count_lanes(Lanes::<8>::new(FACTOR_A, 65), Lanes::new(FACTOR_B, 8921)),
+                serial
+            );
+        }
+    }
+}
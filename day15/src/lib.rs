@@ -0,0 +1,167 @@
+mod batched;
+mod generator;
+mod lanes;
+
+use common::{Answer, Error, Solution};
+pub use generator::{Generator, Judge, FACTOR_A, FACTOR_B, MODULUS};
+pub use lanes::Lanes;
+
+pub struct Day15 {
+    a: u64,
+    b: u64,
+    part1_pairs: usize,
+    part2_pairs: usize,
+    threads: usize,
+    part2_batch: usize,
+}
+
+impl Day15 {
+    /// Judges a different number of pairs in each part.
+    pub fn with_pairs(self, part1: usize, part2: usize) -> Self {
+        Day15 {
+            part1_pairs: part1,
+            part2_pairs: part2,
+            ..self
+        }
+    }
+
+    /// Judges part 1 on `threads` threads, 0 meaning one per CPU.
+    pub fn with_threads(self, threads: usize) -> Self {
+        Day15 { threads, ..self }
+    }
+
+    /// Runs part 2's generators on their own threads, handing values over
+    /// `batch` at a time, 0 meaning in lockstep on one thread.
+    pub fn with_batch(self, batch: usize) -> Self {
+        Day15 {
+            part2_batch: batch,
+            ..self
+        }
+    }
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut starts = input.lines().map(|line| {
+            line.rsplit(' ')
+                .next()
+                .and_then(|num| num.trim().parse().ok())
+                .ok_or_else(|| Error::ParseError(format!("couldn't parse {:?}", line)))
+        });
+        let a = starts
+            .next()
+            .ok_or_else(|| Error::ParseError("no generator a".into()))??;
+        let b = starts
+            .next()
+            .ok_or_else(|| Error::ParseError("no generator b".into()))??;
+        Ok(Self {
+            a,
+            b,
+            part1_pairs: PART1_PAIRS,
+            part2_pairs: PART2_PAIRS,
+            threads: 0,
+            part2_batch: 0,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(part1_threads(self.a, self.b, self.part1_pairs, self.threads).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(part2_batched(self.a, self.b, self.part2_pairs, self.part2_batch).into())
+    }
+}
+
+/// How many pairs each part judges in the puzzle.
+pub const PART1_PAIRS: usize = 40_000_000;
+pub const PART2_PAIRS: usize = 5_000_000;
+
+/// A thread count, 0 meaning one per CPU.
+pub(crate) fn thread_count(threads: usize) -> usize {
+    match threads {
+        0 => std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+        threads => threads,
+    }
+}
+
+pub fn part1(a: u64, b: u64) -> usize {
+    part1_pairs(a, b, PART1_PAIRS)
+}
+
+pub fn part1_pairs(a: u64, b: u64, pairs: usize) -> usize {
+    part1_threads(a, b, pairs, 0)
+}
+
+/// How many pairs part 1 steps at once. Which width is fastest depends on
+/// the machine; `benches/part1.rs` times 4 and 8 lanes to compare.
+pub const PART1_LANES: usize = 8;
+
+/// Steps [`PART1_LANES`] pairs at a time, which the compiler can vectorise,
+/// with the pairs split between `threads` threads.
+pub fn part1_threads(a: u64, b: u64, pairs: usize, threads: usize) -> usize {
+    Judge::new(pairs).count_parallel(
+        Lanes::<PART1_LANES>::new(FACTOR_A, a),
+        Lanes::new(FACTOR_B, b),
+        threads,
+    )
+}
+
+pub fn part2(a: u64, b: u64) -> usize {
+    part2_pairs(a, b, PART2_PAIRS)
+}
+
+/// Runs both picky generators in lockstep on this thread, with nothing to
+/// synchronise. `benches/part2.rs` hasn't shown handing values between
+/// threads to be reliably faster, so [`part2_batched`] is opt-in.
+pub fn part2_pairs(a: u64, b: u64, pairs: usize) -> usize {
+    part2_batched(a, b, pairs, 0)
+}
+
+/// Like [`part2_pairs`], but a nonzero `batch` gives each generator its own
+/// thread, handing values over `batch` at a time.
+pub fn part2_batched(a: u64, b: u64, pairs: usize, batch: usize) -> usize {
+    let judge = Judge::new(pairs);
+    let a = Generator::a(a).multiples_of(4);
+    let b = Generator::b(b).multiples_of(8);
+    match batch {
+        0 => judge.count(a, b),
+        batch => judge.count_batched(a, b, batch),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let part1 = part1(65, 8921);
+        assert_eq!(part1, 588)
+    }
+
+    #[test]
+    fn it_works_part2() {
+        let part2 = part2(65, 8921);
+        assert_eq!(part2, 309)
+    }
+
+    #[test]
+    fn part2_works_in_any_size_of_batch() {
+        for batch in [0, 1, 256, 4096] {
+            assert_eq!(part2_batched(65, 8921, 1056, batch), 1);
+            assert_eq!(part2_batched(65, 8921, 1055, batch), 0);
+        }
+    }
+
+    #[test]
+    fn it_judges_fewer_pairs() {
+        let day = Day15::parse("Generator A starts with 65\nGenerator B starts with 8921")
+            .unwrap()
+            .with_pairs(5, 1056)
+            .with_threads(2);
+        assert_eq!(day.part1().unwrap(), 1.into());
+        assert_eq!(day.part2().unwrap(), 1.into());
+        assert_eq!(day.with_batch(16).part2().unwrap(), 1.into());
+    }
+}
@@ -1,82 +1,54 @@
-fn gen_a(previous: usize) -> usize {
-    (previous * 16807) % 2147483647
-}
-
-fn gen_b(previous: usize) -> usize {
-    (previous * 48271) % 2147483647
-}
-
-const MASK: usize = 0b1111_1111_1111_1111;
-
-fn part1(mut a: usize , mut b: usize) -> usize {
-    (0..40_000_000)
-        .filter(|_| {
-            a = gen_a(a);
-            b = gen_b(b);
-            let a_bits = a & MASK;
-            let b_bits = b & MASK;
-
-            a_bits == b_bits
-        }).count()
-}
-
-use std::thread::spawn;
-use std::sync::mpsc::{self, Sender};
-
-fn part2(a: usize, b: usize) -> usize {
-    let (tx_a, rx_a) = mpsc::channel();
-    let (tx_b, rx_b) = mpsc::channel();
-
-    fn generator(tx: Sender<usize>, starting_value: usize, function: fn(usize) -> usize, modulo: usize) -> impl Fn() {
-        move || {
-            let mut starting_value = starting_value;
-            let mut count = 0;
-            while count < 5_000_000 {
-                starting_value = (function)(starting_value);
-                if starting_value % modulo == 0 {
-                    tx.send(starting_value).expect("couldn't send");
-                    count += 1;
+use common::input::{InputArgs, USAGE};
+use common::Solution;
+use day15::{Day15, PART1_PAIRS, PART2_PAIRS};
+
+/// The input flags, then the part 1 and part 2 pair counts, the part 1
+/// thread count and the part 2 batch size.
+fn parse_args() -> Result<(InputArgs, usize, usize, usize, usize), common::Error> {
+    let mut input_args = InputArgs::default();
+    let (mut part1_pairs, mut part2_pairs) = (PART1_PAIRS, PART2_PAIRS);
+    let mut threads = 0;
+    let mut batch = 0;
+    let mut args = std::env::args().skip(1);
+
+    while let Some(flag) = args.next() {
+        let mut number = || {
+            args.next()
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(|| common::Error::InputError(format!("{} needs a number", flag)))
+        };
+        match flag.as_str() {
+            "--part1-pairs" => part1_pairs = number()?,
+            "--part2-pairs" => part2_pairs = number()?,
+            "--threads" => threads = number()?,
+            "--part2-batch" => batch = number()?,
+            _ => {
+                if !input_args
+                    .parse_flag(&flag, &mut args)
+                    .map_err(common::Error::InputError)?
+                {
+                    return Err(common::Error::InputError(format!(
+                        "unknown flag {:?}\nusage: day15 {} [--part1-pairs <n>] [--part2-pairs <n>] [--threads <n>] [--part2-batch <n>]",
+                        flag, USAGE
+                    )));
                 }
             }
         }
     }
 
-    spawn(generator(tx_a, a, gen_a, 4));
-    spawn(generator(tx_b, b, gen_b, 8));
-
-    let mut count = 0; 
-    while let (Ok(a), Ok(b)) = (rx_a.recv(), rx_b.recv()) {
-        let a_bits = a & MASK;
-        let b_bits = b & MASK;
-
-        if a_bits == b_bits {
-            count += 1;
-        };
-    }
-
-    count
+    Ok((input_args, part1_pairs, part2_pairs, threads, batch))
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let part1 = part1(65, 8921);
-        assert_eq!(part1, 588)
-    }
+fn main() -> Result<(), common::Error> {
+    let (input_args, part1_pairs, part2_pairs, threads, batch) = parse_args()?;
+    let input = input_args.source(15).read()?;
+    let day = Day15::parse(&input)?
+        .with_pairs(part1_pairs, part2_pairs)
+        .with_threads(threads)
+        .with_batch(batch);
 
-    #[test]
-    fn it_works_part2() {
-        let part2 = part2(65, 8921);
-        assert_eq!(part2, 309)
-    }
-}
+    println!("part1: {}", day.part1()?);
+    println!("part2: {}", day.part2()?);
 
-fn main() {
-    let part1 = part1(679, 771);
-    let part2 = part2(679, 771);
-    println!("part1: {}", part1);
-    println!("part2: {}", part2);
+    Ok(())
 }
@@ -0,0 +1,58 @@
+use common::{Answer, Error, Solution};
+
+pub struct Day2(Vec<Vec<u64>>);
+
+impl Solution for Day2 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        input
+            .lines()
+            .map(|line| {
+                line.split('\t')
+                    .map(|num| {
+                        num.parse::<u64>()
+                            .map_err(|_| Error::ParseError(format!("can't parse {:?}", num)))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            })
+            .collect::<Result<Vec<_>, Error>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(part1(&self.0).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(part2(&self.0)?.into())
+    }
+}
+
+pub fn part1(rows: &[Vec<u64>]) -> u64 {
+    rows.iter()
+        .map(|nums| {
+            let min = nums.iter().min().expect("can't find min");
+            let max = nums.iter().max().expect("can't find max");
+            max - min
+        })
+        .sum()
+}
+
+pub fn part2(rows: &[Vec<u64>]) -> Result<u64, Error> {
+    rows.iter()
+        .map(|nums| {
+            nums.iter()
+                .enumerate()
+                .find_map(|(i, num)| {
+                    nums[(i + 1)..].iter().find_map(|other| {
+                        let (big, little) = if other > num { (other, num) } else { (num, other) };
+                        if (*big % *little) == 0 {
+                            Some(big / little)
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .ok_or_else(|| Error::NoSolution(format!("couldn't find {:?}", nums)))
+        })
+        .sum()
+}
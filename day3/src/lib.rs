@@ -0,0 +1,149 @@
+use common::{Answer, Solution};
+
+pub struct Day3(u64);
+
+impl Solution for Day3 {
+    fn parse(input: &str) -> Result<Self, common::Error> {
+        let input = input.trim();
+        input
+            .parse()
+            .map(Self)
+            .map_err(|_| common::Error::ParseError(format!("{:?} isn't a square", input)))
+    }
+
+    fn part1(&self) -> Result<Answer, common::Error> {
+        Ok(solver::part1(self.0 as f64)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, common::Error> {
+        Ok(solver::part2(self.0)?.into())
+    }
+}
+
+pub mod solver {
+    use grid::{Point, SparseGrid};
+
+    #[derive(Debug)]
+    pub enum Error {
+        Part1Error,
+    }
+
+    impl From<Error> for common::Error {
+        fn from(error: Error) -> Self {
+            match error {
+                Error::Part1Error => common::Error::NoSolution("no path to square".into()),
+            }
+        }
+    }
+
+    pub fn part1(input: f64) -> Result<u64, Error> {
+        let sqrt = input.sqrt();
+        let layer = {
+            let mut side = sqrt.ceil() as u64;
+            if side.is_multiple_of(2) {
+                side += 1;
+            }
+            (side - 1) / 2
+        };
+
+        fn layer_fast_travel_point(layer: i64, n: i64) -> i64 {
+            let offset = (2 * layer) * n;
+            let previous_count = ((layer - 1) * 2 + 1).pow(2) - (layer);
+
+            offset + previous_count
+        }
+
+        let layer_delta = (1..5_i64)
+            .map(|i| layer_fast_travel_point(layer as i64, i))
+            .map(|point| (input as i64 - point).abs())
+            .min()
+            .unwrap();
+
+        let layer = layer as i64;
+
+        Ok(layer as u64 + layer_delta as u64)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::part1;
+
+        #[test]
+        fn it_works() {
+            assert_eq!(2, part1(23.0).unwrap());
+            assert_eq!(6, part1(31.0).unwrap());
+            assert_eq!(31, part1(1024.0).unwrap());
+        }
+    }
+
+    #[derive(Hash, Eq, PartialEq, Debug)]
+    struct Cell(Point, u32);
+
+    impl Cell {
+        fn next(&self) -> Self {
+            Cell(self.next_coord(), self.1 + 1)
+        }
+
+        fn next_coord(&self) -> Point {
+            let count = self.1;
+            if count == 1 {
+                return self.0 + Point::new(0, 1);
+            }
+
+            let sqrt = (count as f64).sqrt();
+            let layer = {
+                let mut side = sqrt.ceil() as u64;
+                if side.is_multiple_of(2) {
+                    side += 1;
+                }
+                (side - 1) / 2
+            };
+
+            let side = layer * 2;
+            let last_total = ((layer - 1) * 2 + 1).pow(2);
+
+            let delta = match (count - last_total as u32) / side as u32 {
+                0 => Point::new(1, 0),
+                1 => Point::new(0, -1),
+                2 => Point::new(-1, 0),
+                3 | 4 => Point::new(0, 1),
+                _ => unreachable!(),
+            };
+
+            self.0 + delta
+        }
+
+        fn coord(&self) -> Point {
+            self.0
+        }
+    }
+
+    #[cfg(test)]
+    mod cell_test {
+        use super::Cell;
+        use grid::Point;
+
+        #[test]
+        fn next_works() {
+            assert_eq!(Cell(Point::new(0, 0), 1).next(), Cell(Point::new(0, 1), 2));
+            assert_eq!(Cell(Point::new(0, 1), 2).next(), Cell(Point::new(1, 1), 3));
+            assert_eq!(Cell(Point::new(-1, 1), 9).next(), Cell(Point::new(-1, 2), 10));
+        }
+    }
+
+    pub fn part2(input: u64) -> Result<u64, Error> {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::ORIGIN, 1);
+        let mut value = 1_u64;
+        let mut current_cell = Cell(Point::new(0, 1), 2);
+
+        while value < input {
+            let coord = current_cell.coord();
+            value = grid.surrounding(coord).sum();
+            grid.insert(coord, value);
+            current_cell = current_cell.next();
+        }
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,49 @@
+use common::{Answer, Error, Solution};
+
+pub struct Day4(Vec<Vec<String>>);
+
+impl Solution for Day4 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self(
+            input
+                .lines()
+                .map(|line| line.split(' ').map(|word| word.to_string()).collect())
+                .collect(),
+        ))
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(part1(&self.0).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(part2(&self.0).into())
+    }
+}
+
+pub fn part1(passphrases: &[Vec<String>]) -> usize {
+    passphrases
+        .iter()
+        .filter(|words| {
+            let set = words.iter().collect::<std::collections::HashSet<_>>();
+            words.len() == set.len()
+        })
+        .count()
+}
+
+pub fn part2(passphrases: &[Vec<String>]) -> usize {
+    passphrases
+        .iter()
+        .filter(|words| {
+            let set = words
+                .iter()
+                .map(|span| {
+                    let mut vec: Vec<_> = span.chars().collect();
+                    vec.sort();
+                    vec
+                })
+                .collect::<std::collections::HashSet<_>>();
+            words.len() == set.len()
+        })
+        .count()
+}
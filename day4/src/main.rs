@@ -1,24 +1,11 @@
-fn main() {
-    let input = include_str!("../input.txt");
-    let part1 = input.lines().filter(|line| {
-        let total_words = line.split(' ').count();
-        let set = line.split(' ').collect::<std::collections::HashSet<_>>();
-        total_words == set.iter().count()
-    }).count();
+use common::Solution;
+use day4::Day4;
 
-    let part2 = input.lines().filter(|line| {
-        let total_words = line.split(' ').count();
-        let set = line
-            .split(' ')
-            .map(|span| {
-                let mut vec: Vec<_> = span.chars().collect();
-                vec.sort();
-                vec
-            })
-            .collect::<std::collections::HashSet<_>>();
-        total_words == set.iter().count()
-    }).count();
+fn main() -> Result<(), common::Error> {
+    let input = common::input::load(4)?;
+    let day = Day4::parse(&input)?;
+    println!("Part1: {}", day.part1()?);
+    println!("Part2: {}", day.part2()?);
 
-    println!("Part1: {}", part1);
-    println!("Part2: {}", part2);
+    Ok(())
 }
@@ -0,0 +1,166 @@
+use common::{Answer, Solution};
+use std::marker::PhantomData;
+
+struct Stepper1;
+struct Stepper3;
+
+struct Computer<T> {
+    registers: Vec<i32>,
+    current_position: i32,
+    phantom: PhantomData<T>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    ParseError(String),
+    EmptyProgram,
+}
+
+impl From<Error> for common::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::ParseError(reason) => common::Error::ParseError(reason),
+            Error::EmptyProgram => common::Error::ParseError("no jump offsets".into()),
+        }
+    }
+}
+
+fn parse_registers(input: &str) -> Result<Vec<i32>, Error> {
+    let registers = input
+        .lines()
+        .map(|line| {
+            line.parse::<i32>()
+                .map_err(|e| Error::ParseError(format!("invalid jump offset {:?}: {}", line, e)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if registers.is_empty() {
+        return Err(Error::EmptyProgram);
+    }
+    Ok(registers)
+}
+
+impl<T> From<Vec<i32>> for Computer<T> {
+    fn from(registers: Vec<i32>) -> Self {
+        Computer {
+            registers,
+            current_position: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> TryFrom<&str> for Computer<T> {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(parse_registers(input)?.into())
+    }
+}
+
+trait Stepper {
+    fn step(&mut self) -> Option<usize>;
+}
+
+impl Stepper for Computer<Stepper1> {
+    fn step(&mut self) -> Option<usize> {
+        let new_position = self.current_position + self.registers[self.current_position as usize];
+        self.registers[self.current_position as usize] += 1;
+
+        if (0..self.registers.len() as i32).contains(&new_position) {
+            self.current_position = new_position;
+            Some(new_position as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Computer<T>
+where
+    Computer<T>: Stepper,
+{
+    fn run(&mut self) -> usize {
+        let mut steps: usize = 1;
+        while self.step().is_some() {
+            steps += 1;
+        }
+        steps
+    }
+}
+
+impl Stepper for Computer<Stepper3> {
+    fn step(&mut self) -> Option<usize> {
+        let current_value = self.registers[self.current_position as usize];
+        let new_position = self.current_position + current_value;
+
+        let register_change = if current_value >= 3 { -1 } else { 1 };
+        self.registers[self.current_position as usize] += register_change;
+
+        if (0..self.registers.len() as i32).contains(&new_position) {
+            self.current_position = new_position;
+            Some(new_position as usize)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Day5(Vec<i32>);
+
+impl Solution for Day5 {
+    fn parse(input: &str) -> Result<Self, common::Error> {
+        Ok(Self(parse_registers(input)?))
+    }
+
+    fn part1(&self) -> Result<Answer, common::Error> {
+        let mut computer: Computer<Stepper1> = self.0.clone().into();
+        Ok(computer.run().into())
+    }
+
+    fn part2(&self) -> Result<Answer, common::Error> {
+        let mut computer: Computer<Stepper3> = self.0.clone().into();
+        Ok(computer.run().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let input = r#"0
+3
+0
+1
+-3"#;
+
+        let mut computer: Computer<Stepper1> = Computer::try_from(input).unwrap();
+        assert_eq!(computer.run(), 5);
+    }
+
+    #[test]
+    fn it_rejects_bad_programs() {
+        assert!(matches!(Day5::parse(""), Err(common::Error::ParseError(_))));
+        assert!(matches!(
+            Day5::parse("0\nfoo"),
+            Err(common::Error::ParseError(reason)) if reason.contains("\"foo\"")
+        ));
+    }
+
+    #[test]
+    fn it_exits_on_jumping_backwards_off_the_start() {
+        let day = Day5::parse("-1").unwrap();
+        assert_eq!(day.part1().unwrap(), 1.into());
+        assert_eq!(day.part2().unwrap(), 1.into());
+    }
+
+    #[test]
+    fn it_exits_on_landing_at_the_end() {
+        // jumping to exactly one past the last offset leaves the list
+        let mut computer: Computer<Stepper1> = vec![2, 0].into();
+        assert_eq!(computer.run(), 1);
+        let mut computer: Computer<Stepper3> = vec![1, 1].into();
+        assert_eq!(computer.run(), 2);
+    }
+}
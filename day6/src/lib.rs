@@ -0,0 +1,134 @@
+use common::{Answer, Solution};
+
+#[derive(Debug)]
+pub enum Error {
+    ParseError,
+}
+
+impl From<Error> for common::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::ParseError => common::Error::ParseError("invalid bank".into()),
+        }
+    }
+}
+
+pub fn parse_banks(input: &str) -> Result<Vec<u8>, Error> {
+    input
+        .trim()
+        .split('\t')
+        .map(|num| num.parse().map_err(|_| Error::ParseError))
+        .collect()
+}
+
+pub struct Day6(Vec<u8>);
+
+impl Solution for Day6 {
+    fn parse(input: &str) -> Result<Self, common::Error> {
+        Ok(Self(parse_banks(input)?))
+    }
+
+    fn part1(&self) -> Result<Answer, common::Error> {
+        Ok(solver::part1(self.0.clone())?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, common::Error> {
+        Ok(solver::part2(self.0.clone())?.into())
+    }
+}
+
+pub mod solver {
+    pub use crate::Error;
+
+    pub fn find_loop(mut banks: Vec<u8>) -> Result<(Vec<u8>, usize), Error> {
+        let mut set: std::collections::HashSet<Vec<u8>> = std::collections::HashSet::new();
+        set.insert(banks.clone());
+
+        let mut loop_count = 1;
+
+        loop {
+            let max = *banks.iter().max().expect("no max");
+            let index = banks.iter().position(|&a| a == max).unwrap();
+            banks[index] = 0;
+
+            // increase all banks by divisible amount
+            let banks_increase = max as usize / banks.len();
+            banks.iter_mut().for_each(|b| *b += banks_increase as u8);
+
+            // increase all banks after max by 1 for remainder
+            let remainder = max as usize % banks.len();
+            for n in 0..remainder {
+                let pos = (index + n + 1) % banks.len();
+                banks[pos] += 1;
+            }
+
+            if !set.insert(banks.clone()) {
+                break;
+            } else {
+                loop_count += 1;
+            }
+        }
+
+        Ok((banks, loop_count))
+    }
+
+    pub fn find_repeat(mut banks: Vec<u8>, sig: Vec<u8>) -> Result<(Vec<u8>, usize), Error> {
+        let mut loop_count = 0;
+
+        loop {
+            let max = *banks.iter().max().expect("no max");
+            let index = banks.iter().position(|&a| a == max).unwrap();
+            banks[index] = 0;
+
+            // increase all banks by divisible amount
+            let banks_increase = max as usize / banks.len();
+            banks.iter_mut().for_each(|b| *b += banks_increase as u8);
+
+            // increase all banks after max by 1 for remainder
+            let remainder = max as usize % banks.len();
+            for n in 0..remainder {
+                let pos = (index + n + 1) % banks.len();
+                banks[pos] += 1;
+            }
+
+            loop_count += 1;
+            if banks == sig {
+                break;
+            }
+        }
+
+        Ok((banks, loop_count))
+    }
+
+    pub fn part1(banks: Vec<u8>) -> Result<usize, Error> {
+        let (_, count) = find_loop(banks)?;
+        Ok(count)
+    }
+
+    pub fn part2(banks: Vec<u8>) -> Result<usize, Error> {
+        let (banks, _) = find_loop(banks)?;
+        let (_, count) = find_repeat(banks.clone(), banks)?;
+        Ok(count)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::{parse_banks, solver};
+
+        #[test]
+        fn part1() {
+            let input = "0	2	7	0";
+            let banks = parse_banks(input).unwrap();
+            let part1 = solver::part1(banks);
+            assert_eq!(part1.unwrap(), 5);
+        }
+
+        #[test]
+        fn part2() {
+            let input = "0	2	7	0";
+            let banks = parse_banks(input).unwrap();
+            let part1 = solver::part2(banks);
+            assert_eq!(part1.unwrap(), 4);
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use common::{Answer, Solution};
+use itertools::Itertools;
+
+pub struct Dag;
+
+type NodeMap = std::collections::HashMap<String, Node>;
+pub type RecursiveResult = Result<i32, (i32, Vec<i32>)>;
+
+impl Dag {
+    /// The one tower with nothing below it.
+    pub fn root(graph: &Graph) -> Result<String, Error> {
+        let mut towers = graph::Graph::new();
+        for (name, node) in &graph.nodes {
+            towers.add_node(name.as_str());
+            for leaf in node.nodes.iter().flatten() {
+                towers.add_edge(name.as_str(), leaf.as_str());
+            }
+        }
+
+        match towers.roots()[..] {
+            [root] => Ok(root.to_string()),
+            [] => Err(Error::RootError("no tower is at the bottom".into())),
+            ref roots => Err(Error::RootError(format!(
+                "{} towers have nothing below them",
+                roots.len()
+            ))),
+        }
+    }
+
+    pub fn build_dag(graph: &Graph) -> Result<RecursiveResult, Error> {
+        let root = Self::root(graph)?;
+        let Graph { nodes } = graph;
+
+        fn sum_vals(node: &Node, map: &NodeMap) -> Result<RecursiveResult, Error> {
+            let Some(nodes) = &node.nodes else {
+                return Ok(Ok(node.num));
+            };
+            let mut children = vec![];
+            for name in nodes {
+                let node = map
+                    .get(name)
+                    .ok_or_else(|| Error::RootError(format!("no tower called {:?}", name)))?;
+                match sum_vals(node, map)? {
+                    Ok(value) => children.push((value, node.num)),
+                    found => return Ok(found),
+                }
+            }
+
+            if children.iter().map(|(value, _)| value).unique().count() > 1 {
+                let mut counts = std::collections::HashMap::new();
+                for (value, _) in children.iter() {
+                    *counts.entry(*value).or_insert(0) += 1;
+                }
+
+                // one changed weight only balances the tower when every other
+                // child agrees on the same total
+                let unfixable = || {
+                    Error::NoSolution(format!(
+                        "can't tell which tower above {:?} has the wrong weight",
+                        node.name
+                    ))
+                };
+                if counts.len() != 2 {
+                    return Err(unfixable());
+                }
+                let wrong = counts
+                    .iter()
+                    .find_map(|(&value, &count)| (count == 1).then_some(value))
+                    .ok_or_else(unfixable)?;
+                let right = counts
+                    .iter()
+                    .find_map(|(&value, &count)| (count > 1).then_some(value))
+                    .ok_or_else(unfixable)?;
+                let diff = wrong - right;
+
+                let val = children
+                    .iter()
+                    .find_map(|&(value, num)| (value == wrong).then_some(num))
+                    .ok_or_else(unfixable)?;
+
+                Ok(Err((
+                    val - diff,
+                    children.into_iter().map(|(value, _)| value).collect(),
+                )))
+            } else {
+                Ok(Ok(
+                    node.num + children.iter().map(|(count, _)| count).sum::<i32>()
+                ))
+            }
+        }
+
+        let root = nodes
+            .get(&root)
+            .ok_or_else(|| Error::RootError(format!("no tower called {:?}", root)))?;
+        sum_vals(root, nodes)
+    }
+}
+
+pub struct Graph {
+    nodes: std::collections::HashMap<String, Node>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    NodeParseError(&'static str),
+    RootError(String),
+    NoSolution(String),
+}
+
+impl From<Error> for common::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NodeParseError(reason) => common::Error::ParseError(reason.into()),
+            Error::RootError(reason) => common::Error::ParseError(reason),
+            Error::NoSolution(reason) => common::Error::NoSolution(reason),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    num: i32,
+    nodes: Option<Vec<String>>,
+}
+
+impl TryFrom<&str> for Node {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (name, rest) = value
+            .split_once(' ')
+            .ok_or(Error::NodeParseError("no name"))?;
+        // num needs to be processed
+        let mut nodes = None;
+        let num = if let Some((num, rest)) = rest.split_once(' ') {
+            let (_, rest) = rest
+                .split_once(' ')
+                .ok_or(Error::NodeParseError("no arrow"))?;
+
+            nodes = Some(
+                rest.split(", ")
+                    .map(|s| s.to_owned())
+                    .collect::<Vec<String>>(),
+            );
+            num
+        } else {
+            rest
+        };
+
+        Ok(Self {
+            num: num
+                .strip_prefix('(')
+                .and_then(|num| num.strip_suffix(')'))
+                .ok_or(Error::NodeParseError("weight isn't in brackets"))?
+                .parse()
+                .map_err(|_| Error::NodeParseError("can't parse"))?,
+            name: name.to_string(),
+            nodes,
+        })
+    }
+}
+
+impl TryFrom<&str> for Graph {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            nodes: input
+                .lines()
+                .map(|line| {
+                    let node: Node = line.try_into()?;
+                    Ok((node.name.clone(), node))
+                })
+                .collect::<Result<NodeMap, Error>>()?,
+        })
+    }
+}
+
+pub struct Day7(Graph);
+
+impl Solution for Day7 {
+    fn parse(input: &str) -> Result<Self, common::Error> {
+        Ok(Self(input.try_into()?))
+    }
+
+    fn part1(&self) -> Result<Answer, common::Error> {
+        Ok(Dag::root(&self.0)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, common::Error> {
+        match Dag::build_dag(&self.0)? {
+            Err((weight, _)) => Ok(weight.into()),
+            Ok(_) => Err(common::Error::NoSolution("tower is already balanced".into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Dag, Day7, Graph};
+    use common::Solution;
+
+    #[test]
+    fn it_works() {
+        let input = r#"pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)"#;
+        let graph: Graph = input.try_into().unwrap();
+
+        let part1 = Dag::root(&graph).unwrap();
+        assert_eq!("tknk", part1);
+    }
+
+    #[test]
+    fn it_finds_unbalanced() {
+        let input = r#"pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)"#;
+        let graph: Graph = input.try_into().unwrap();
+
+        let part2 = Dag::build_dag(&graph).unwrap();
+        if let Err((n, _)) = part2 {
+            assert_eq!(n, 60);
+        } else {
+            panic!("expected an unbalanced tower")
+        }
+    }
+
+    #[test]
+    fn it_needs_one_root() {
+        let day = Day7::parse("").unwrap();
+        assert!(matches!(day.part1(), Err(common::Error::ParseError(_))));
+        assert!(matches!(day.part2(), Err(common::Error::ParseError(_))));
+
+        let two = Day7::parse("pbga (66)\nxhth (57)").unwrap();
+        assert!(matches!(
+            two.part1(),
+            Err(common::Error::ParseError(reason)) if reason.starts_with("2 towers")
+        ));
+
+        let cycle = Day7::parse("pbga (66) -> xhth\nxhth (57) -> pbga").unwrap();
+        assert!(matches!(cycle.part1(), Err(common::Error::ParseError(_))));
+    }
+
+    #[test]
+    fn it_rejects_weights_without_brackets() {
+        for input in ["a b", "a (1", "a 1)", "a (x)", "a () -> b"] {
+            assert!(
+                matches!(Day7::parse(input), Err(common::Error::ParseError(_))),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_needs_every_tower_above() {
+        let missing = Day7::parse("a (1) -> b").unwrap();
+        assert!(matches!(
+            missing.part2(),
+            Err(common::Error::ParseError(reason)) if reason.contains("\"b\"")
+        ));
+    }
+
+    #[test]
+    fn it_needs_a_fixable_tower() {
+        // either of two differing towers could be the wrong one
+        let two = Day7::parse("a (1) -> b, c\nb (2)\nc (3)").unwrap();
+        assert!(matches!(two.part2(), Err(common::Error::NoSolution(_))));
+
+        // no single change balances three different totals
+        let three = Day7::parse("a (1) -> b, c, d\nb (2)\nc (3)\nd (4)").unwrap();
+        assert!(matches!(three.part2(), Err(common::Error::NoSolution(_))));
+
+        // any number of agreeing towers pins down the odd one out
+        let four = Day7::parse("a (1) -> b, c, d, e\nb (2)\nc (2)\nd (2)\ne (5)").unwrap();
+        assert_eq!(four.part2().unwrap(), 2.into());
+    }
+}
@@ -0,0 +1,290 @@
+use common::{Answer, Solution};
+
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum Error {
+    ParseError(String),
+    ConditionalParseError(String),
+    OperationParseError(String),
+}
+
+impl From<Error> for common::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::ParseError(reason)
+            | Error::ConditionalParseError(reason)
+            | Error::OperationParseError(reason) => common::Error::ParseError(reason),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Computer {
+    registers: std::collections::HashMap<String, i32>,
+    instructions: Vec<Instruction>,
+    pointer: usize,
+    max_register: i32,
+}
+
+impl TryFrom<&str> for Computer {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let instructions = value
+            .lines()
+            .map(|line| line.try_into())
+            .collect::<Result<Vec<Instruction>, Error>>()?;
+
+        Ok(Self {
+            instructions,
+            registers: std::collections::HashMap::new(),
+            pointer: 0,
+            max_register: 0,
+        })
+    }
+}
+
+impl Computer {
+    fn step(&mut self) {
+        let val = self.instructions[self.pointer].call(&mut self.registers);
+        if &self.max_register < val {
+            self.max_register = *val;
+        }
+    }
+
+    fn run(&mut self) {
+        for _ in 0..self.instructions.len() {
+            self.step();
+            self.pointer += 1;
+        }
+    }
+
+    /// The largest register, if any instruction wrote one.
+    fn scan(&self) -> Option<i32> {
+        self.registers.values().max().copied()
+    }
+}
+
+#[derive(Clone)]
+enum Operator {
+    Dec,
+    Inc,
+}
+
+impl TryFrom<&str> for Operator {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "dec" => Operator::Dec,
+            "inc" => Operator::Inc,
+            _ => return Err(Error::OperationParseError("unkown operator".into())),
+        })
+    }
+}
+
+#[derive(Clone)]
+enum Condition {
+    LessThan,
+    LessThanEqual,
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanEqual,
+}
+
+impl TryFrom<&str> for Condition {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        use Condition::*;
+
+        Ok(match value {
+            "<" => LessThan,
+            "<=" => LessThanEqual,
+            "==" => Equal,
+            "!=" => NotEqual,
+            ">" => GreaterThan,
+            ">=" => GreaterThanEqual,
+            _ => return Err(Error::ConditionalParseError("unknown condition".into())),
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Conditional {
+    lh: String,
+    rh: i32,
+    condition: Condition,
+}
+
+impl TryFrom<&str> for Conditional {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut iter = value.split(" ");
+        let lh = iter
+            .next()
+            .ok_or_else(|| Error::ConditionalParseError("no lh".into()))?
+            .to_string();
+        let condition: Condition = iter
+            .next()
+            .ok_or_else(|| Error::ConditionalParseError("no condition".into()))?
+            .try_into()?;
+        let rh = iter
+            .next()
+            .ok_or_else(|| Error::ConditionalParseError("no rh".into()))?
+            .parse()
+            .map_err(|_| Error::ConditionalParseError("no rh".into()))?;
+
+        Ok(Self { lh, condition, rh })
+    }
+}
+
+#[derive(Clone)]
+struct Operation {
+    register: String,
+    operator: Operator,
+    value: i32,
+}
+
+impl Operation {
+    fn call<'reg>(&self, registers: &'reg mut std::collections::HashMap<String, i32>) -> &'reg i32 {
+        self.operator
+            .call(registers.entry(self.register.clone()), self.value);
+
+        registers.get(&self.register).unwrap()
+    }
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut iter = value.split(" ");
+        let register = iter
+            .next()
+            .ok_or_else(|| Error::OperationParseError("no register".into()))?
+            .to_string();
+        let operator: Operator = iter
+            .next()
+            .ok_or_else(|| Error::OperationParseError("no register".into()))?
+            .try_into()?;
+        let value = iter
+            .next()
+            .ok_or_else(|| Error::OperationParseError("no value".into()))?
+            .parse()
+            .map_err(|_| Error::OperationParseError("no value".into()))?;
+
+        Ok(Self {
+            register,
+            operator,
+            value,
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Instruction {
+    operation: Operation,
+    conditional: Conditional,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (operation, conditional) = value
+            .split_once(" if ")
+            .ok_or_else(|| Error::ParseError("no if statement".into()))?;
+        Ok(Self {
+            operation: operation.try_into()?,
+            conditional: conditional.try_into()?,
+        })
+    }
+}
+
+pub struct Day8(Computer);
+
+impl Solution for Day8 {
+    fn parse(input: &str) -> Result<Self, common::Error> {
+        Ok(Self(input.try_into()?))
+    }
+
+    fn part1(&self) -> Result<Answer, common::Error> {
+        let mut computer = self.0.clone();
+        computer.run();
+        computer
+            .scan()
+            .map(Answer::from)
+            .ok_or_else(|| common::Error::NoSolution("no register was written".into()))
+    }
+
+    fn part2(&self) -> Result<Answer, common::Error> {
+        let mut computer = self.0.clone();
+        computer.run();
+        Ok(computer.max_register.into())
+    }
+}
+
+impl Instruction {
+    fn call<'reg>(&self, registers: &'reg mut std::collections::HashMap<String, i32>) -> &'reg i32 {
+        if self.conditional.call(registers) {
+            self.operation.call(registers)
+        } else {
+            &0
+        }
+    }
+}
+impl Conditional {
+    fn call(&self, registers: &std::collections::HashMap<String, i32>) -> bool {
+        let lh = registers.get(&self.lh).unwrap_or(&0);
+        self.condition.call(lh, &self.rh)
+    }
+}
+impl Condition {
+    fn call(&self, lh: &i32, rh: &i32) -> bool {
+        match self {
+            Condition::LessThan => lh < rh,
+            Condition::LessThanEqual => lh <= rh,
+            Condition::Equal => lh == rh,
+            Condition::NotEqual => lh != rh,
+            Condition::GreaterThan => lh > rh,
+            Condition::GreaterThanEqual => lh >= rh,
+        }
+    }
+}
+impl Operator {
+    fn call(&self, register: std::collections::hash_map::Entry<String, i32>, value: i32) {
+        match self {
+            Operator::Dec => *register.or_insert(0) -= value,
+            Operator::Inc => *register.or_insert(0) += value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let day = Day8::parse(
+            "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10",
+        )
+        .unwrap();
+        assert_eq!(day.part1().unwrap(), 1.into());
+        assert_eq!(day.part2().unwrap(), 10.into());
+    }
+
+    #[test]
+    fn it_needs_a_register() {
+        for input in ["", "a inc 1 if b > 0"] {
+            let day = Day8::parse(input).unwrap();
+            assert!(matches!(day.part1(), Err(common::Error::NoSolution(_))));
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::UnionFind;
+
+/// A directed graph with nodes kept in insertion order. Edges point from a
+/// node to its neighbours; for undirected problems add them both ways.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            nodes: vec![],
+            index: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Hash + Eq + Clone> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `node` if it's new, returning its index either way.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.index.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.index.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        index
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push(to);
+    }
+
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn neighbours(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.index
+            .get(node)
+            .into_iter()
+            .flat_map(|&index| &self.edges[index])
+            .map(|&index| &self.nodes[index])
+    }
+
+    /// Every node reachable from `start`, nearest first.
+    pub fn bfs(&self, start: &N) -> Walk<'_, N> {
+        Walk::new(self, start, false)
+    }
+
+    /// Every node reachable from `start`, following each branch to its end
+    /// before backtracking.
+    pub fn dfs(&self, start: &N) -> Walk<'_, N> {
+        Walk::new(self, start, true)
+    }
+
+    fn in_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.len()];
+        for &to in self.edges.iter().flatten() {
+            degrees[to] += 1;
+        }
+        degrees
+    }
+
+    /// The nodes nothing points to.
+    pub fn roots(&self) -> Vec<&N> {
+        self.in_degrees()
+            .into_iter()
+            .zip(&self.nodes)
+            .filter(|&(degree, _)| degree == 0)
+            .map(|(_, node)| node)
+            .collect()
+    }
+
+    /// Orders the nodes so every edge points forwards, or `None` if there's
+    /// a cycle.
+    pub fn topological_order(&self) -> Option<Vec<&N>> {
+        let mut degrees = self.in_degrees();
+        let mut ready = (0..self.len())
+            .filter(|&index| degrees[index] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(index) = ready.pop_front() {
+            order.push(&self.nodes[index]);
+            for &to in &self.edges[index] {
+                degrees[to] -= 1;
+                if degrees[to] == 0 {
+                    ready.push_back(to);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Groups the nodes that are connected ignoring edge direction, each
+    /// group and the groups themselves in insertion order.
+    pub fn components(&self) -> Vec<Vec<&N>> {
+        let mut sets = UnionFind::new(self.len());
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &to in edges {
+                sets.union(from, to);
+            }
+        }
+
+        let mut components: Vec<Vec<&N>> = vec![];
+        let mut by_root = HashMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let root = sets.find(index);
+            let component = *by_root.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[component].push(node);
+        }
+        components
+    }
+}
+
+/// A breadth or depth first walk from one node, visiting each node once.
+pub struct Walk<'a, N> {
+    graph: &'a Graph<N>,
+    frontier: VecDeque<usize>,
+    seen: Vec<bool>,
+    depth_first: bool,
+}
+
+impl<'a, N: Hash + Eq> Walk<'a, N> {
+    fn new(graph: &'a Graph<N>, start: &N, depth_first: bool) -> Self {
+        Walk {
+            graph,
+            frontier: graph.index.get(start).copied().into_iter().collect(),
+            seen: vec![false; graph.nodes.len()],
+            depth_first,
+        }
+    }
+}
+
+impl<'a, N> Iterator for Walk<'a, N> {
+    type Item = &'a N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let index = if self.depth_first {
+                self.frontier.pop_back()?
+            } else {
+                self.frontier.pop_front()?
+            };
+            if std::mem::replace(&mut self.seen[index], true) {
+                continue;
+            }
+
+            let edges = &self.graph.edges[index];
+            if self.depth_first {
+                // pushed in reverse so the first neighbour is walked first
+                self.frontier.extend(edges.iter().rev());
+            } else {
+                self.frontier.extend(edges);
+            }
+            return Some(&self.graph.nodes[index]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn graph(edges: &[(u8, u8)]) -> Graph<u8> {
+        let mut graph = Graph::new();
+        for &(from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn it_walks() {
+        let graph = graph(&[(0, 1), (0, 2), (1, 3), (2, 4), (3, 0), (5, 0)]);
+        assert_eq!(
+            graph.bfs(&0).copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            graph.dfs(&0).copied().collect::<Vec<_>>(),
+            vec![0, 1, 3, 2, 4]
+        );
+        assert_eq!(graph.bfs(&4).count(), 1);
+        assert_eq!(graph.bfs(&9).count(), 0);
+    }
+
+    #[test]
+    fn it_finds_roots() {
+        let graph = graph(&[(1, 2), (1, 3), (3, 4), (5, 4)]);
+        assert_eq!(graph.roots(), vec![&1, &5]);
+    }
+
+    #[test]
+    fn it_orders_topologically() {
+        let graph = graph(&[(3, 1), (1, 2), (3, 2), (4, 3)]);
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order, vec![&4, &3, &1, &2]);
+
+        let cyclic = self::graph(&[(1, 2), (2, 3), (3, 1)]);
+        assert_eq!(cyclic.topological_order(), None);
+    }
+
+    #[test]
+    fn it_finds_components() {
+        let mut graph = graph(&[(0, 2), (3, 4), (4, 5), (6, 4)]);
+        graph.add_node(1);
+        assert_eq!(
+            graph.components(),
+            vec![vec![&0, &2], vec![&3, &4, &5, &6], vec![&1]]
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod graph;
+mod union_find;
+
+pub use graph::{Graph, Walk};
+pub use union_find::UnionFind;
@@ -0,0 +1,147 @@
+use crate::Point;
+
+/// A dense grid of flags packed 64 to a word, `(0, 0)` top left. Points
+/// outside the bounds, negative coordinates included, read as unset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        BitGrid {
+            width,
+            height,
+            words: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    /// Builds a grid from rows of flags, which must all be the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = bool>>) -> Self {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let width = rows.first().map_or(0, Vec::len);
+
+        let mut grid = BitGrid::new(width, rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            assert_eq!(row.len(), width, "row {} has a different width", y);
+            for (x, &set) in row.iter().enumerate() {
+                grid.set(Point::new(x as i32, y as i32), set);
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> bool {
+        self.index(point)
+            .is_some_and(|i| self.words[i / 64] >> (i % 64) & 1 == 1)
+    }
+
+    /// Sets or clears a flag, returning `false` if the point is outside the
+    /// grid.
+    pub fn set(&mut self, point: Point, set: bool) -> bool {
+        let Some(i) = self.index(point) else {
+            return false;
+        };
+        if set {
+            self.words[i / 64] |= 1 << (i % 64);
+        } else {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+        true
+    }
+
+    /// How many flags are set.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    /// The set points, row by row, skipping empty words.
+    pub fn ones(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words.iter().enumerate().flat_map(move |(w, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let i = w * 64 + word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(Point::new((i % self.width) as i32, (i / self.width) as i32))
+            })
+        })
+    }
+
+    /// Draws the grid one row per line.
+    pub fn render(&self, cell: impl Fn(bool) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for point in self.points() {
+            output.push(cell(self.get(point)));
+            if point.x as usize == self.width - 1 {
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_stays_in_bounds() {
+        let mut grid = BitGrid::new(70, 2);
+        assert!(grid.set(Point::new(69, 1), true));
+        assert!(!grid.set(Point::new(70, 1), true));
+        assert!(!grid.set(Point::new(-1, 0), true));
+
+        assert!(grid.get(Point::new(69, 1)));
+        assert!(!grid.get(Point::new(0, 2)));
+        assert!(!grid.get(Point::new(-1, 1)));
+        assert_eq!(grid.count_ones(), 1);
+
+        grid.set(Point::new(69, 1), false);
+        assert_eq!(grid.count_ones(), 0);
+    }
+
+    #[test]
+    fn it_lists_set_points() {
+        let grid = BitGrid::from_rows([[true, false, true], [false, false, true]]);
+        assert_eq!(
+            grid.ones().collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(2, 0), Point::new(2, 1)]
+        );
+        assert_eq!(grid.render(|set| if set { '#' } else { '.' }), "#.#\n..#\n");
+    }
+}
@@ -0,0 +1,187 @@
+use crate::Point;
+
+/// A dense grid with fixed bounds, `(0, 0)` top left. Lookups outside the
+/// bounds, negative coordinates included, return `None` rather than wrapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - before;
+            assert_eq!(
+                *width.get_or_insert(row_width),
+                row_width,
+                "row {} has a different width",
+                height
+            );
+            height += 1;
+        }
+
+        Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Sets a cell, returning `false` if the point is outside the grid.
+    pub fn set(&mut self, point: Point, value: T) -> bool {
+        match self.get_mut(point) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, and a zero width grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The orthogonal neighbours of `point` that are inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours4().filter(|&p| self.contains(p))
+    }
+
+    /// All surrounding neighbours of `point` that are inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours8().filter(|&p| self.contains(p))
+    }
+
+    /// Draws the grid one row per line.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).chain(Some('\n')).collect::<String>())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid() -> Grid<bool> {
+        Grid::from_rows([[true, false, true], [false, true, true]])
+    }
+
+    #[test]
+    fn it_maps() {
+        let grid = grid().map(|&used| if used { 1 } else { 0 });
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 0, 1], &[0, 1, 1]]
+        );
+    }
+
+    #[test]
+    fn it_stays_in_bounds() {
+        let grid = grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&true));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn it_finds_neighbours_in_bounds() {
+        let grid = grid();
+        let corner = grid.neighbours4(Point::ORIGIN).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Point::RIGHT, Point::DOWN]);
+        assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn it_sets_cells() {
+        let mut grid = Grid::new(2, 2, '.');
+        assert!(grid.set(Point::new(1, 1), '#'));
+        assert!(!grid.set(Point::new(2, 1), '#'));
+        assert_eq!(grid.render(|&c| c), "..\n.#\n");
+    }
+
+    #[test]
+    fn it_renders() {
+        assert_eq!(
+            grid().render(|&used| if used { '#' } else { '.' }),
+            "#.#\n.##\n"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_rejects_ragged_rows() {
+        Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    }
+}
@@ -0,0 +1,9 @@
+mod bits;
+mod bounded;
+mod point;
+mod sparse;
+
+pub use bits::BitGrid;
+pub use bounded::Grid;
+pub use point::Point;
+pub use sparse::SparseGrid;
@@ -0,0 +1,103 @@
+use std::ops::{Add, AddAssign, Sub};
+
+/// A position on an integer grid. `y` grows downwards, like rows on screen.
+#[derive(Hash, Eq, PartialEq, Copy, Clone, Debug, Default, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    /// Offsets to the four orthogonal neighbours.
+    pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::LEFT, Point::RIGHT, Point::DOWN];
+
+    /// Offsets to all eight surrounding neighbours, diagonals included.
+    pub const SURROUNDING: [Point; 8] = [
+        Point::new(-1, -1),
+        Point::new(0, -1),
+        Point::new(1, -1),
+        Point::new(-1, 0),
+        Point::new(1, 0),
+        Point::new(-1, 1),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Self::ORTHOGONAL
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Self::SURROUNDING
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_adds() {
+        assert_eq!(Point::new(1, 2) + Point::new(-3, 4), Point::new(-2, 6));
+        assert_eq!(Point::new(1, 2) - Point::new(1, 2), Point::ORIGIN);
+    }
+
+    #[test]
+    fn it_finds_neighbours() {
+        let neighbours = Point::ORIGIN.neighbours4().collect::<Vec<_>>();
+        assert_eq!(neighbours.len(), 4);
+        assert!(neighbours.iter().all(|p| p.manhattan(Point::ORIGIN) == 1));
+
+        let neighbours = Point::new(5, 5).neighbours8().collect::<Vec<_>>();
+        assert_eq!(neighbours.len(), 8);
+        assert!(!neighbours.contains(&Point::new(5, 5)));
+        assert!(neighbours.contains(&Point::new(6, 6)));
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use crate::Point;
+
+/// An unbounded grid that only stores the cells that have been set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// The set cells among the eight surrounding `point`.
+    pub fn surrounding(&self, point: Point) -> impl Iterator<Item = &T> {
+        point.neighbours8().filter_map(|p| self.get(p))
+    }
+
+    /// The top left and bottom right corners of the set cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+
+    /// Draws the bounding box of the set cells, using `empty` for the gaps.
+    pub fn render(&self, cell: impl Fn(&T) -> char, empty: char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+
+        (min.y..=max.y)
+            .flat_map(|y| {
+                (min.x..=max.x)
+                    .map(move |x| Point::new(x, y))
+                    .map(|p| self.get(p).map_or(empty, &cell))
+                    .chain(Some('\n'))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_grows_in_any_direction() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::new(-2, 1), 'a');
+        grid.insert(Point::new(1, -1), 'b');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(1, 1))));
+        assert_eq!(grid.render(|&c| c, '.'), "...b\n....\na...\n");
+    }
+
+    #[test]
+    fn it_sums_surrounding_cells() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::ORIGIN, 1);
+        grid.insert(Point::new(1, 1), 2);
+        grid.insert(Point::new(3, 3), 4);
+        assert_eq!(grid.surrounding(Point::new(1, 0)).sum::<i32>(), 3);
+    }
+
+    #[test]
+    fn it_renders_nothing_when_empty() {
+        assert_eq!(SparseGrid::<char>::new().render(|&c| c, '.'), "");
+    }
+}
@@ -0,0 +1,47 @@
+//! Hashes a day14-sized batch of inputs with the slice-reversing rounds and with the
+//! reference implementation, and reports how many hashes each manages per
+//! second. Run with `cargo bench -p knothash [-- <batch size>]`.
+
+use std::time::{Duration, Instant};
+
+fn throughput(name: &str, inputs: &[Vec<u8>], hash: fn(&[u8]) -> [u8; 16]) -> f64 {
+    let mut samples = (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            for input in inputs {
+                std::hint::black_box(hash(std::hint::black_box(input)));
+            }
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    samples.sort();
+
+    let median = samples[samples.len() / 2];
+    let per_second = inputs.len() as f64 / median.as_secs_f64();
+    println!(
+        "{:<10} {:>8} hashes in {:>10.2?}  {:>10.0} hashes/s",
+        name,
+        inputs.len(),
+        median,
+        per_second
+    );
+    per_second
+}
+
+fn main() {
+    let batch = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(128 * 100);
+    let inputs = (0..batch)
+        .map(|i| format!("flqrgnkx-{}", i).into_bytes())
+        .collect::<Vec<_>>();
+
+    for input in inputs.iter().take(128) {
+        assert_eq!(knothash::hash(input), knothash::reference::hash(input));
+    }
+
+    let reference = throughput("reference", &inputs, knothash::reference::hash);
+    let contiguous = throughput("contiguous", &inputs, knothash::hash);
+    println!("speedup    {:.2}x", contiguous / reference);
+}
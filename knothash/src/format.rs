@@ -0,0 +1,63 @@
+/// Two lowercase hex digits per byte, as the puzzle prints hashes.
+pub fn to_hex(hash: &[u8]) -> String {
+    hash.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Eight `0`/`1` digits per byte, most significant bit first.
+pub fn to_binary(hash: &[u8]) -> String {
+    hash.iter().map(|byte| format!("{:08b}", byte)).collect()
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 with `=` padding.
+pub fn to_base64(hash: &[u8]) -> String {
+    let mut output = String::new();
+    for chunk in hash.chunks(3) {
+        let block = chunk
+            .iter()
+            .chain(&[0, 0])
+            .take(3)
+            .fold(0u32, |block, &byte| block << 8 | byte as u32);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(BASE64[(block >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+/// The bits of `hash`, most significant bit of each byte first.
+pub fn bits(hash: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    hash.iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| byte >> bit & 1 == 1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_formats() {
+        let hash = [0xa0, 0xc2, 0x01, 0x70];
+        assert_eq!(to_hex(&hash), "a0c20170");
+        assert_eq!(to_binary(&hash), "10100000110000100000000101110000");
+        assert_eq!(to_base64(&hash), "oMIBcA==");
+        assert_eq!(
+            bits(&hash[..1]).collect::<Vec<_>>(),
+            vec![true, false, true, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn it_pads_base64() {
+        assert_eq!(to_base64(b""), "");
+        assert_eq!(to_base64(b"f"), "Zg==");
+        assert_eq!(to_base64(b"fo"), "Zm8=");
+        assert_eq!(to_base64(b"foo"), "Zm9v");
+        assert_eq!(to_base64(b"foobar"), "Zm9vYmFy");
+    }
+}
@@ -0,0 +1,94 @@
+use std::hash::Hasher;
+use std::io;
+
+use crate::Params;
+
+/// Builds a knot hash from input fed in pieces.
+///
+/// Every round replays the whole input, so nothing can be hashed until the
+/// input is complete: bytes are kept until [`KnotHasher::finalize`]. Cloning a
+/// hasher part way through shares the common prefix, e.g. a day14 key with a
+/// different row number after it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnotHasher {
+    input: Vec<u8>,
+}
+
+impl KnotHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) -> &mut Self {
+        self.input.extend_from_slice(bytes.as_ref());
+        self
+    }
+
+    /// The hash of everything written so far. The hasher can keep taking
+    /// input afterwards.
+    pub fn finalize(&self) -> [u8; 16] {
+        Params::default()
+            .hash(&self.input)
+            .try_into()
+            .expect("256 marks fold into 16 bytes")
+    }
+
+    pub fn reset(&mut self) {
+        self.input.clear();
+    }
+}
+
+impl Hasher for KnotHasher {
+    /// The first 8 bytes of the hash, big endian.
+    fn finish(&self) -> u64 {
+        let hash = self.finalize();
+        u64::from_be_bytes(hash[..8].try_into().expect("hash has 16 bytes"))
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+impl io::Write for KnotHasher {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.update(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::to_hex;
+    use std::io::Write;
+
+    #[test]
+    fn it_works() {
+        let mut hasher = KnotHasher::new();
+        hasher.update("1,").update(b"2,3");
+        assert_eq!(
+            to_hex(&hasher.finalize()),
+            "3efbe78a8d82f29979031a4aa0b16a9d"
+        );
+        assert_eq!(Hasher::finish(&hasher), 0x3efbe78a8d82f299);
+
+        hasher.reset();
+        assert_eq!(hasher.finalize(), KnotHasher::new().finalize());
+    }
+
+    #[test]
+    fn it_streams() {
+        let mut hasher = KnotHasher::new();
+        std::io::copy(&mut &b"AoC 2017"[..], &mut hasher).unwrap();
+        let mut written = KnotHasher::new();
+        write!(written, "AoC {}", 2017).unwrap();
+
+        assert_eq!(hasher.finalize(), crate::hash(b"AoC 2017"));
+        assert_eq!(written, hasher);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::BLOCK_SIZE;
+
+/// A circle of marks being tied, carrying the current position and skip size
+/// from one round to the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Knot {
+    list: Vec<u8>,
+    position: usize,
+    skip: usize,
+    /// `skip` modulo the list size.
+    skip_step: usize,
+}
+
+impl Knot {
+    /// A circle of `size` marks numbered from 0. Marks are bytes, so there can
+    /// be at most 256 of them.
+    pub fn new(size: usize) -> Self {
+        assert!(
+            (1..=256).contains(&size),
+            "list size {} isn't between 1 and 256",
+            size
+        );
+        Knot {
+            list: (0..size).map(|mark| mark as u8).collect(),
+            position: 0,
+            skip: 0,
+            skip_step: 0,
+        }
+    }
+
+    pub fn list(&self) -> &[u8] {
+        &self.list
+    }
+
+    pub fn into_list(self) -> Vec<u8> {
+        self.list
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn skip(&self) -> usize {
+        self.skip
+    }
+
+    /// Reverses the `length` marks from the current position, wrapping round
+    /// the circle, then moves on by `length` plus the skip size.
+    ///
+    /// Panics if `length` is longer than the list.
+    pub fn tie(&mut self, length: usize) {
+        self.round([length]);
+    }
+
+    /// Ties each of `lengths` in turn.
+    ///
+    /// Reversals are done on contiguous slices rather than by swapping pairs
+    /// with modulo indexing: a span that wraps past the end is gathered into
+    /// a buffer on the stack, reversed there and scattered back.
+    pub fn round(&mut self, lengths: impl IntoIterator<Item = usize>) {
+        let len = self.list.len();
+
+        for length in lengths {
+            assert!(length <= len, "length {} exceeds list size {}", length, len);
+            let start = self.position;
+            let end = start + length;
+
+            if end <= len {
+                self.list[start..end].reverse();
+            } else {
+                reverse_wrapped(&mut self.list, start, end - len);
+            }
+
+            // end + skip can be several laps on, and dividing on every length
+            // costs more than the reversal
+            self.position = end + self.skip_step;
+            while self.position >= len {
+                self.position -= len;
+            }
+            self.skip += 1;
+            self.skip_step += 1;
+            if self.skip_step == len {
+                self.skip_step = 0;
+            }
+        }
+    }
+
+    /// XORs each block of 16 marks into one byte.
+    pub fn dense(&self) -> Vec<u8> {
+        self.fold(BLOCK_SIZE)
+    }
+
+    /// XORs each block of `block_size` marks into one byte, the last block
+    /// taking whatever marks are left over.
+    pub fn fold(&self, block_size: usize) -> Vec<u8> {
+        assert!(block_size > 0, "block size must be positive");
+        self.list.chunks(block_size).map(xor_block).collect()
+    }
+}
+
+/// Reverses the span running from `start` to the end of `list` and on
+/// round to `tail`, through a buffer big enough for any list.
+fn reverse_wrapped(list: &mut [u8], start: usize, tail: usize) {
+    let head = list.len() - start;
+    let length = head + tail;
+    let mut span = [0; 256];
+    span[..head].copy_from_slice(&list[start..]);
+    span[head..length].copy_from_slice(&list[..tail]);
+    span[..length].reverse();
+    list[start..].copy_from_slice(&span[..head]);
+    list[..tail].copy_from_slice(&span[head..length]);
+}
+
+/// XORs a block together, a whole 16 byte block at a time when it can.
+fn xor_block(block: &[u8]) -> u8 {
+    match <[u8; 16]>::try_from(block) {
+        Ok(block) => {
+            let mut x = u128::from_ne_bytes(block);
+            x ^= x >> 64;
+            x ^= x >> 32;
+            x ^= x >> 16;
+            x ^= x >> 8;
+            x as u8
+        }
+        Err(_) => block.iter().fold(0, |a, b| a ^ b),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tie_works() {
+        let mut knot = Knot::new(5);
+        knot.tie(3);
+        assert_eq!(knot.list(), &[2, 1, 0, 3, 4]);
+        assert_eq!((knot.position(), knot.skip()), (3, 1));
+
+        knot.tie(4);
+        assert_eq!(knot.list(), &[4, 3, 0, 1, 2]);
+        assert_eq!((knot.position(), knot.skip()), (3, 2));
+    }
+
+    #[test]
+    fn dense_works() {
+        let mut knot = Knot::new(16);
+        assert_eq!(knot.dense(), vec![0]);
+
+        knot.list = vec![65, 27, 9, 1, 4, 3, 40, 50, 91, 7, 6, 0, 2, 5, 68, 22];
+        assert_eq!(knot.dense(), vec![64]);
+
+        knot.list.truncate(5);
+        assert_eq!(knot.dense(), vec![65 ^ 27 ^ 9 ^ 1 ^ 4]);
+    }
+
+    #[test]
+    fn it_wraps_round() {
+        let mut knot = Knot::new(5);
+        knot.round([3, 4, 1, 5]);
+        assert_eq!(knot.list(), &[3, 4, 2, 1, 0]);
+        assert_eq!((knot.position(), knot.skip()), (4, 4));
+    }
+
+    #[test]
+    fn it_reverses_wrapped_spans() {
+        for len in 1..=12 {
+            for start in 1..len {
+                for tail in 1..=start {
+                    let mut list = (0..len as u8).collect::<Vec<_>>();
+                    reverse_wrapped(&mut list, start, tail);
+
+                    let mut expected = (0..len as u8).collect::<Vec<_>>();
+                    let span = (start..len).chain(0..tail).collect::<Vec<_>>();
+                    for (&i, &j) in span.iter().zip(span.iter().rev()) {
+                        expected[i] = j as u8;
+                    }
+                    assert_eq!(list, expected, "{} from {} to {}", len, start, tail);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "length 6 exceeds list size 5")]
+    fn it_rejects_long_lengths() {
+        Knot::new(5).tie(6);
+    }
+}
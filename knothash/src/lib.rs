@@ -0,0 +1,112 @@
+mod format;
+mod hasher;
+mod knot;
+mod params;
+pub mod reference;
+mod trace;
+
+pub use format::{bits, to_base64, to_binary, to_hex};
+pub use hasher::KnotHasher;
+pub use knot::Knot;
+pub use params::Params;
+pub use trace::{Step, Trace};
+
+/// The number of marks on the standard circle.
+pub const LIST_SIZE: usize = 256;
+/// Lengths appended to every input before hashing.
+pub const SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
+/// How many rounds the full hash runs.
+pub const ROUNDS: usize = 64;
+/// How many marks are XORed into each byte of the dense hash.
+pub const BLOCK_SIZE: usize = 16;
+
+/// Runs a single round of `lengths` over a circle of `size` marks, as in the
+/// first half of the puzzle.
+pub fn sparse_round(lengths: &[usize], size: usize) -> Vec<u8> {
+    Params::single_round(size).sparse(lengths).into_list()
+}
+
+/// The full knot hash of `input`: its bytes plus [`SUFFIX`] as lengths, over
+/// [`ROUNDS`] rounds, folded down to 16 bytes.
+pub fn hash(input: &[u8]) -> [u8; 16] {
+    KnotHasher::new().update(input).finalize()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// The examples published with the puzzle.
+    const VECTORS: [(&str, &str); 4] = [
+        ("", "a2582a3a0e66e6e86e3812dcb672a272"),
+        ("AoC 2017", "33efeb34ea91902bb2f59c9920caa6cd"),
+        ("1,2,3", "3efbe78a8d82f29979031a4aa0b16a9d"),
+        ("1,2,4", "63960835bcdc130f0b66d7ff4f6a5a8e"),
+    ];
+
+    #[test]
+    fn it_works() {
+        for (input, expected) in VECTORS {
+            assert_eq!(to_hex(&hash(input.as_bytes())), expected, "{:?}", input);
+            assert_eq!(to_hex(&reference::hash(input.as_bytes())), expected);
+            assert_eq!(
+                to_hex(&KnotHasher::new().update(input).finalize()),
+                expected
+            );
+        }
+    }
+
+    fn is_permutation(list: &[u8], size: usize) -> bool {
+        let mut sorted = list.to_vec();
+        sorted.sort_unstable();
+        sorted.into_iter().map(usize::from).eq(0..size)
+    }
+
+    proptest! {
+        #[test]
+        fn sparse_hash_is_a_permutation(input in vec(any::<u8>(), 0..64)) {
+            let lengths = input.iter().map(|&length| length as usize).collect::<Vec<_>>();
+            let knot = Params::default().sparse(&lengths);
+            prop_assert!(is_permutation(knot.list(), 256));
+        }
+
+        #[test]
+        fn every_variant_is_a_permutation(
+            list_size in 1..=256usize,
+            rounds in 0..4usize,
+            lengths in vec(0..=256usize, 0..32),
+        ) {
+            let lengths = lengths
+                .into_iter()
+                .map(|length| length % (list_size + 1))
+                .collect::<Vec<_>>();
+            let params = Params {
+                list_size,
+                rounds,
+                suffix: vec![],
+                ..Params::default()
+            };
+            prop_assert!(is_permutation(params.sparse(&lengths).list(), list_size));
+        }
+
+        #[test]
+        fn it_agrees_with_the_reference(input in vec(any::<u8>(), 0..64)) {
+            prop_assert_eq!(hash(&input), reference::hash(&input));
+        }
+
+        #[test]
+        fn it_hashes_the_same_in_pieces(input in vec(any::<u8>(), 0..32), split in 0..=32usize) {
+            let (head, tail) = input.split_at(split.min(input.len()));
+            let mut hasher = KnotHasher::new();
+            hasher.update(head).update(tail);
+            prop_assert_eq!(hasher.finalize(), hash(&input));
+        }
+    }
+
+    #[test]
+    fn sparse_round_works() {
+        assert_eq!(sparse_round(&[3, 4, 1, 5], 5), vec![3, 4, 2, 1, 0]);
+    }
+}
@@ -0,0 +1,336 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::ExitCode;
+
+use knothash::KnotHasher;
+
+const USAGE: &str = "usage: knothash [--format <hex|binary|base64>] [<file>...]
+       knothash --check [--format <hex|binary|base64>] [<file>...]
+       knothash --trace [text|json] [<file>]
+
+Prints the knot hash of each file's bytes, or of stdin when there are no
+files or a file is `-`. With --check, reads `<digest>  <file>` lines and
+reports whether each file still hashes to its digest. With --trace, prints
+every step of hashing one file instead, as text frames or JSON.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Hex,
+    Binary,
+    Base64,
+}
+
+impl Format {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "hex" => Ok(Format::Hex),
+            "binary" => Ok(Format::Binary),
+            "base64" => Ok(Format::Base64),
+            _ => Err(format!("unknown format {:?}", name)),
+        }
+    }
+
+    fn digest(&self, hash: &[u8]) -> String {
+        match self {
+            Format::Hex => knothash::to_hex(hash),
+            Format::Binary => knothash::to_binary(hash),
+            Format::Base64 => knothash::to_base64(hash),
+        }
+    }
+
+    /// Hex digests match in either case; base64 is case sensitive.
+    fn matches(&self, hash: &[u8], digest: &str) -> bool {
+        match self {
+            Format::Hex => self.digest(hash).eq_ignore_ascii_case(digest),
+            Format::Binary | Format::Base64 => self.digest(hash) == digest,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TraceFormat {
+    Text,
+    Json,
+}
+
+impl TraceFormat {
+    fn render(&self, input: &[u8]) -> String {
+        let lengths = input
+            .iter()
+            .map(|&length| length as usize)
+            .collect::<Vec<_>>();
+        let trace = knothash::Params::default().trace(&lengths);
+        match self {
+            TraceFormat::Text => trace.render(),
+            TraceFormat::Json => trace.to_json() + "\n",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    format: Format,
+    check: bool,
+    trace: Option<TraceFormat>,
+    files: Vec<String>,
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.peekable();
+    let mut format = Format::Hex;
+    let mut check = false;
+    let mut trace = None;
+    let mut files = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                format = Format::parse(&args.next().ok_or("--format needs a value")?)?
+            }
+            "--check" | "-c" => check = true,
+            "--trace" | "-t" => {
+                // the format is optional, so only take a word that names one
+                trace = Some(match args.peek().map(String::as_str) {
+                    Some("json") => TraceFormat::Json,
+                    Some("text") => TraceFormat::Text,
+                    _ => {
+                        trace = Some(TraceFormat::Text);
+                        continue;
+                    }
+                });
+                args.next();
+            }
+            "-" => files.push(arg),
+            _ if arg.starts_with('-') => return Err(format!("unknown flag {:?}", arg)),
+            _ => files.push(arg),
+        }
+    }
+
+    if files.is_empty() {
+        files.push("-".into());
+    }
+    if trace.is_some() && (check || files.len() > 1) {
+        return Err("--trace takes a single file and can't be used with --check".into());
+    }
+
+    Ok(Args {
+        format,
+        check,
+        trace,
+        files,
+    })
+}
+
+fn open(name: &str) -> io::Result<Box<dyn Read>> {
+    if name == "-" {
+        Ok(Box::new(io::stdin()))
+    } else {
+        Ok(Box::new(File::open(name)?))
+    }
+}
+
+fn hash_file(name: &str) -> io::Result<[u8; 16]> {
+    let mut hasher = KnotHasher::new();
+    io::copy(&mut open(name)?, &mut hasher)?;
+    Ok(hasher.finalize())
+}
+
+/// Splits a `<digest>  <file>` line as written by `knothash`.
+fn parse_check_line(line: &str) -> Option<(&str, &str)> {
+    let (digest, file) = line.split_once(' ')?;
+    let file = file.strip_prefix(' ').unwrap_or(file);
+    (!digest.is_empty() && !file.is_empty()).then_some((digest, file))
+}
+
+fn print_hashes(args: &Args) -> Result<(), String> {
+    let mut failures = 0;
+    for name in &args.files {
+        match hash_file(name) {
+            Ok(hash) => println!("{}  {}", args.format.digest(&hash), name),
+            Err(e) => {
+                eprintln!("knothash: {}: {}", name, e);
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        Err(format!(
+            "{} of {} files couldn't be read",
+            failures,
+            args.files.len()
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn print_trace(name: &str, format: TraceFormat) -> Result<(), String> {
+    let mut input = vec![];
+    open(name)
+        .and_then(|mut file| file.read_to_end(&mut input))
+        .map_err(|e| format!("{}: {}", name, e))?;
+    // traces are long enough to be piped into a pager or `head`, so a
+    // reader that stops early isn't an error
+    match io::stdout().write_all(format.render(&input).as_bytes()) {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(e.to_string()),
+        _ => Ok(()),
+    }
+}
+
+fn check(args: &Args) -> Result<(), String> {
+    let mut checked = 0;
+    let mut mismatches = 0;
+    let mut unreadable = 0;
+
+    for list in &args.files {
+        let reader = BufReader::new(open(list).map_err(|e| format!("{}: {}", list, e))?);
+        for (number, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| format!("{}: {}", list, e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (expected, name) = parse_check_line(&line)
+                .ok_or_else(|| format!("{}:{}: expected `<digest>  <file>`", list, number + 1))?;
+
+            checked += 1;
+            match hash_file(name) {
+                Ok(hash) if args.format.matches(&hash, expected) => {
+                    println!("{}: OK", name)
+                }
+                Ok(_) => {
+                    println!("{}: FAILED", name);
+                    mismatches += 1;
+                }
+                Err(e) => {
+                    println!("{}: FAILED open or read ({})", name, e);
+                    unreadable += 1;
+                }
+            }
+        }
+    }
+
+    match (mismatches, unreadable) {
+        (0, 0) => Ok(()),
+        _ => Err(format!(
+            "{} of {} files didn't match, {} couldn't be read",
+            mismatches, checked, unreadable
+        )),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match args.trace {
+        Some(format) => print_trace(&args.files[0], format),
+        None if args.check => check(&args),
+        None => print_hashes(&args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("knothash: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(line: &str) -> Result<Args, String> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn it_parses_args() {
+        assert_eq!(
+            args("").unwrap(),
+            Args {
+                format: Format::Hex,
+                check: false,
+                trace: None,
+                files: vec!["-".into()],
+            }
+        );
+        assert_eq!(
+            args("--check --format base64 sums.txt -").unwrap(),
+            Args {
+                format: Format::Base64,
+                check: true,
+                trace: None,
+                files: vec!["sums.txt".into(), "-".into()],
+            }
+        );
+        assert!(args("--format octal").is_err());
+        assert!(args("--quiet").is_err());
+    }
+
+    #[test]
+    fn it_parses_trace_args() {
+        let trace = |line| args(line).map(|args| (args.trace, args.files));
+        assert_eq!(
+            trace("--trace"),
+            Ok((Some(TraceFormat::Text), vec!["-".into()]))
+        );
+        assert_eq!(
+            trace("--trace json input.txt"),
+            Ok((Some(TraceFormat::Json), vec!["input.txt".into()]))
+        );
+        assert_eq!(
+            trace("-t input.txt"),
+            Ok((Some(TraceFormat::Text), vec!["input.txt".into()]))
+        );
+        assert!(args("--trace a.txt b.txt").is_err());
+        assert!(args("--trace --check").is_err());
+    }
+
+    #[test]
+    fn it_renders_traces() {
+        let text = TraceFormat::Text.render(b"1,2,3");
+        assert!(text.starts_with("round 1, length 49 at 0, skip 0:\n"));
+        assert!(text.contains("round 64, length 23 at "));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&TraceFormat::Json.render(b"1,2,3")).unwrap();
+        assert_eq!(json["list_size"], 256);
+        // the five input bytes and five suffix lengths, for 64 rounds
+        assert_eq!(json["steps"].as_array().unwrap().len(), 64 * 10);
+    }
+
+    #[test]
+    fn it_parses_check_lines() {
+        assert_eq!(
+            parse_check_line("3efbe78a8d82f29979031a4aa0b16a9d  input.txt"),
+            Some(("3efbe78a8d82f29979031a4aa0b16a9d", "input.txt"))
+        );
+        assert_eq!(
+            parse_check_line("oMIBcA== my file.txt"),
+            Some(("oMIBcA==", "my file.txt"))
+        );
+        assert_eq!(parse_check_line("3efbe78a8d82f29979031a4aa0b16a9d"), None);
+    }
+
+    #[test]
+    fn it_formats_digests() {
+        let hash = knothash::hash(b"1,2,3");
+        assert_eq!(
+            Format::Hex.digest(&hash),
+            "3efbe78a8d82f29979031a4aa0b16a9d"
+        );
+        assert_eq!(Format::Binary.digest(&hash).len(), 128);
+        assert_eq!(Format::Base64.digest(&hash), "Pvvnio2C8pl5AxpKoLFqnQ==");
+
+        assert!(Format::Hex.matches(&hash, "3EFBE78A8D82F29979031A4AA0B16A9D"));
+        assert!(!Format::Base64.matches(&hash, "PVVNIO2C8PL5AXPKOLFQNQ=="));
+    }
+}
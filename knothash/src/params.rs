@@ -0,0 +1,101 @@
+use crate::{Knot, BLOCK_SIZE, LIST_SIZE, ROUNDS, SUFFIX};
+
+/// Everything the knot hash leaves fixed, so variants can run through the
+/// same engine. The default is the puzzle's standard hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// Marks on the circle, at most 256.
+    pub list_size: usize,
+    pub rounds: usize,
+    /// Lengths appended after the input's own.
+    pub suffix: Vec<u8>,
+    /// How many marks are XORed into each byte of the dense hash.
+    pub block_size: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            list_size: LIST_SIZE,
+            rounds: ROUNDS,
+            suffix: SUFFIX.to_vec(),
+            block_size: BLOCK_SIZE,
+        }
+    }
+}
+
+impl Params {
+    /// One round of the lengths as given, as in the first half of the puzzle.
+    pub fn single_round(list_size: usize) -> Self {
+        Params {
+            list_size,
+            rounds: 1,
+            suffix: vec![],
+            ..Params::default()
+        }
+    }
+
+    /// Ties `lengths` followed by the suffix, once per round.
+    ///
+    /// Panics if the list size isn't between 1 and 256, or a length is
+    /// longer than the list.
+    pub fn sparse(&self, lengths: &[usize]) -> Knot {
+        let lengths = lengths
+            .iter()
+            .copied()
+            .chain(self.suffix.iter().map(|&length| length as usize))
+            .collect::<Vec<_>>();
+
+        let mut knot = Knot::new(self.list_size);
+        for _ in 0..self.rounds {
+            knot.round(lengths.iter().copied());
+        }
+        knot
+    }
+
+    /// Hashes the bytes of `input` as lengths and folds the result into
+    /// blocks.
+    pub fn hash(&self, input: &[u8]) -> Vec<u8> {
+        let lengths = input
+            .iter()
+            .map(|&length| length as usize)
+            .collect::<Vec<_>>();
+        self.sparse(&lengths).fold(self.block_size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_defaults_to_the_standard_hash() {
+        assert_eq!(
+            Params::default().hash(b"AoC 2017"),
+            crate::hash(b"AoC 2017")
+        );
+    }
+
+    #[test]
+    fn it_runs_a_single_round() {
+        let knot = Params::single_round(5).sparse(&[3, 4, 1, 5]);
+        assert_eq!(knot.list(), &[3, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn it_varies() {
+        let params = Params {
+            list_size: 32,
+            rounds: 2,
+            suffix: vec![3],
+            block_size: 8,
+        };
+        let hash = params.hash(b"\x05\x01");
+        assert_eq!(hash.len(), 4);
+
+        let mut knot = Knot::new(32);
+        knot.round([5, 1, 3]);
+        knot.round([5, 1, 3]);
+        assert_eq!(hash, knot.fold(8));
+    }
+}
@@ -0,0 +1,41 @@
+//! The straightforward knot hash, swapping one pair of marks at a time with
+//! modulo indexing. Kept to check and benchmark [`crate::Knot`] against.
+
+use crate::{BLOCK_SIZE, LIST_SIZE, ROUNDS, SUFFIX};
+
+pub fn hash(input: &[u8]) -> [u8; 16] {
+    let lengths = input.iter().chain(&SUFFIX).map(|&length| length as usize);
+    let mut list = (0..LIST_SIZE).map(|mark| mark as u8).collect::<Vec<_>>();
+    let mut position = 0;
+    let mut skip = 0;
+
+    for _ in 0..ROUNDS {
+        for length in lengths.clone() {
+            for i in 0..(length / 2) {
+                let left = (position + i) % LIST_SIZE;
+                let right = (position + length - 1 - i) % LIST_SIZE;
+                list.swap(left, right);
+            }
+            position += length + skip;
+            skip += 1;
+        }
+    }
+
+    let mut hash = [0; 16];
+    for (byte, block) in hash.iter_mut().zip(list.chunks(BLOCK_SIZE)) {
+        *byte = block.iter().fold(0, |a, b| a ^ b);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_agrees_with_knot() {
+        for input in ["", "1,2,3", "AoC 2017", "flqrgnkx-127"] {
+            assert_eq!(hash(input.as_bytes()), crate::hash(input.as_bytes()));
+        }
+    }
+}
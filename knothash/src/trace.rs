@@ -0,0 +1,155 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{Knot, Params};
+
+/// The circle after one length was tied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step {
+    /// Counting from 1.
+    pub round: usize,
+    pub length: usize,
+    /// The current position before tying.
+    pub position: usize,
+    pub skip: usize,
+    /// The indices that were reversed, in order, wrapping round the circle.
+    pub span: Vec<usize>,
+    pub list: Vec<u8>,
+    /// The current position after moving on.
+    pub next_position: usize,
+}
+
+impl fmt::Display for Step {
+    /// One frame in the puzzle's notation: the reversed span in parentheses,
+    /// the next position in brackets.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "round {}, length {} at {}, skip {}:",
+            self.round, self.length, self.position, self.skip
+        )?;
+
+        let first = self.span.first();
+        let last = self.span.last();
+        for (index, mark) in self.list.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            if first == Some(&index) {
+                f.write_str("(")?;
+            }
+            if index == self.next_position {
+                write!(f, "[{}]", mark)?;
+            } else {
+                write!(f, "{}", mark)?;
+            }
+            if last == Some(&index) {
+                f.write_str(")")?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
+/// Every step of a run, for debugging or teaching the hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Trace {
+    pub list_size: usize,
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    /// Text frames, one per step, separated by blank lines.
+    pub fn render(&self) -> String {
+        self.steps
+            .iter()
+            .map(Step::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("traces always serialize")
+    }
+}
+
+impl Params {
+    /// Runs [`Params::sparse`] and records the circle after every length.
+    pub fn trace(&self, lengths: &[usize]) -> Trace {
+        let lengths = lengths
+            .iter()
+            .copied()
+            .chain(self.suffix.iter().map(|&length| length as usize))
+            .collect::<Vec<_>>();
+
+        let mut knot = Knot::new(self.list_size);
+        let mut steps = vec![];
+        for round in 1..=self.rounds {
+            for &length in &lengths {
+                let position = knot.position();
+                let skip = knot.skip();
+                knot.tie(length);
+                steps.push(Step {
+                    round,
+                    length,
+                    position,
+                    skip,
+                    span: (position..position + length)
+                        .map(|index| index % self.list_size)
+                        .collect(),
+                    list: knot.list().to_vec(),
+                    next_position: knot.position(),
+                });
+            }
+        }
+
+        Trace {
+            list_size: self.list_size,
+            steps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_traces_the_example() {
+        let trace = Params::single_round(5).trace(&[3, 4, 1, 5]);
+        assert_eq!(trace.steps.len(), 4);
+        assert_eq!(trace.steps[1].span, vec![3, 4, 0, 1]);
+        assert_eq!(trace.steps[3].list, vec![3, 4, 2, 1, 0]);
+        assert_eq!(
+            trace.steps.last().unwrap().list,
+            Params::single_round(5).sparse(&[3, 4, 1, 5]).into_list()
+        );
+
+        assert_eq!(
+            trace.render(),
+            "round 1, length 3 at 0, skip 0:
+(2 1 0) [3] 4
+
+round 1, length 4 at 3, skip 1:
+4 3) 0 ([1] 2
+
+round 1, length 1 at 3, skip 2:
+4 [3] 0 (1) 2
+
+round 1, length 5 at 1, skip 3:
+3) (4 2 1 [0]
+"
+        );
+    }
+
+    #[test]
+    fn it_exports_json() {
+        let trace = Params::single_round(3).trace(&[2]);
+        let json: serde_json::Value = serde_json::from_str(&trace.to_json()).unwrap();
+        assert_eq!(json["list_size"], 3);
+        assert_eq!(json["steps"][0]["span"], serde_json::json!([0, 1]));
+        assert_eq!(json["steps"][0]["list"], serde_json::json!([1, 0, 2]));
+        assert_eq!(json["steps"][0]["next_position"], 2);
+    }
+}